@@ -17,16 +17,28 @@ use crate::section6_idioms;
 use crate::section7_concurrency;
 use crate::section8_crates;
 
+use serde::Serialize;
+use std::collections::HashSet;
+use std::fmt;
 use std::io::{self, Write};
+use std::sync::atomic::{AtomicBool, Ordering};
 
 /// Interactive menu system for running lecture demos
 pub fn run_interactive_demo() {
-    println!("🦀 RUST LECTURE DEMONSTRATION SYSTEM 🦀");
+    println!("{}", MAIN_BANNER);
     println!("======================================");
     println!();
     
+    // Consecutive invalid choices; reset whenever a valid choice is made
+    let mut invalid_attempts = 0;
+    // Sections run to completion during this session
+    let mut completed_sections: HashSet<u8> = HashSet::new();
+    
     loop {
-        print_menu();
+        // Re-prompt straight away after a typo instead of redrawing the menu
+        if invalid_attempts == 0 {
+            print_menu(&completed_sections);
+        }
         
         let choice = get_user_input("Enter your choice (1-8, 'e' for enhanced, or 'q' to quit): ");
         
@@ -34,86 +46,120 @@ pub fn run_interactive_demo() {
             "1" => {
                 clear_screen();
                 section1_basics::run_all_demos();
+                completed_sections.insert(1);
                 wait_for_enter();
             }
             "2" => {
                 clear_screen();
                 section2_ownership::run_all_demos();
+                completed_sections.insert(2);
                 wait_for_enter();
             }
             "3" => {
                 clear_screen();
                 section3_borrowing::run_all_demos();
+                completed_sections.insert(3);
                 wait_for_enter();
             }
             "4" => {
                 clear_screen();
                 section4_traits::run_all_demos();
+                completed_sections.insert(4);
                 wait_for_enter();
             }
             "5" => {
                 clear_screen();
                 section5_enums::run_all_demos();
+                completed_sections.insert(5);
                 wait_for_enter();
             }
             "6" => {
                 clear_screen();
                 section6_idioms::run_all_demos();
+                completed_sections.insert(6);
                 wait_for_enter();
             }
             "7" => {
                 clear_screen();
                 section7_concurrency::run_all_demos();
+                completed_sections.insert(7);
                 wait_for_enter();
             }
             "8" => {
                 clear_screen();
                 section8_crates::run_all_demos();
+                completed_sections.insert(8);
                 wait_for_enter();
             }
             "all" | "ALL" => {
                 clear_screen();
                 run_all_sections();
+                completed_sections.extend(1..=8);
                 wait_for_enter();
             }
             "e" | "E" | "enhanced" => {
                 clear_screen();
                 run_enhanced_navigation_mode();
             }
+            "step" | "STEP" => {
+                lecture_utils::set_step_mode(!lecture_utils::step_mode());
+            }
             "q" | "Q" | "quit" | "exit" => {
-                println!("Thanks for using the Rust lecture demo system! 🦀");
+                println!(concat!("Thanks for using the Rust lecture demo system! ", crab!()));
                 break;
             }
             _ => {
-                println!("Invalid choice. Please try again.");
-                wait_for_enter();
+                invalid_attempts += 1;
+                println!("Invalid choice '{}'. Please try again.", choice.trim());
+                if invalid_attempts >= 3 {
+                    print_menu_help();
+                    invalid_attempts = 0;
+                }
+                continue;
             }
         }
         
+        invalid_attempts = 0;
         clear_screen();
     }
 }
 
+/// Print the full list of menu choices and demos after repeated typos
+fn print_menu_help() {
+    println!();
+    println!("{}", MENU_HELP);
+    println!("In enhanced mode ('e') you can step through these demos:");
+    for section in 1..=8 {
+        individual_demos::print_section_demos(section);
+    }
+    println!();
+}
+
 /// Print the main menu
-fn print_menu() {
-    println!("📚 LECTURE SECTIONS:");
-    println!("  1. Basic Syntax and Constructs");
-    println!("  2. Ownership and Move Semantics");
-    println!("  3. Borrowing, References, and Lifetimes");
-    println!("  4. Trait System and Generics");
-    println!("  5. Enums, Pattern Matching, Option & Result");
-    println!("  6. Idiomatic Patterns & Utilities");
-    println!("  7. Fearless Concurrency");
-    println!("  8. Popular Crate Examples (20 crates)");
+fn print_menu(completed: &HashSet<u8>) {
+    let done = |section: u8| if completed.contains(&section) { DONE_MARK } else { "" };
+    
+    println!("{}", SECTIONS_HEADING);
+    println!("  1. Basic Syntax and Constructs{}", done(1));
+    println!("  2. Ownership and Move Semantics{}", done(2));
+    println!("  3. Borrowing, References, and Lifetimes{}", done(3));
+    println!("  4. Trait System and Generics{}", done(4));
+    println!("  5. Enums, Pattern Matching, Option & Result{}", done(5));
+    println!("  6. Idiomatic Patterns & Utilities{}", done(6));
+    println!("  7. Fearless Concurrency{}", done(7));
+    println!("  8. Popular Crate Examples (28 demos){}", done(8));
     println!();
-    println!("🚀 SPECIAL OPTIONS:");
+    println!("{}", OPTIONS_HEADING);
     println!("  all - Run all sections sequentially");
     println!("  e   - Enhanced navigation mode (individual demos)");
+    println!("  step - Pause inside verbose demos (currently {})", if lecture_utils::step_mode() { "on" } else { "off" });
     println!("  q   - Quit");
     println!();
-    println!("💡 Enhanced mode allows you to navigate individual demos with:");
+    println!("{}", ENHANCED_HINT);
     println!("   Enter = Next demo, Backspace = Previous demo");
     println!();
+    println!("{} {}/8 sections done ({}%)", PROGRESS_LABEL, completed.len(), completed.len() * 100 / 8);
+    println!();
 }
 
 /// Get user input with a prompt
@@ -121,28 +167,65 @@ fn get_user_input(prompt: &str) -> String {
     print!("{}", prompt);
     io::stdout().flush().unwrap();
     
-    let mut input = String::new();
-    io::stdin().read_line(&mut input).unwrap();
-    input
+    lecture_utils::read_line()
 }
 
 /// Wait for user to press Enter
 fn wait_for_enter() {
     println!();
     println!("Press Enter to continue...");
-    let mut input = String::new();
-    io::stdin().read_line(&mut input).unwrap();
+    lecture_utils::read_line();
+}
+
+/// Whether clear_screen() actually clears; disabled with --no-clear
+static CLEAR_SCREEN: AtomicBool = AtomicBool::new(true);
+
+/// Enable or disable screen clearing between demos
+pub fn set_clear_screen(enabled: bool) {
+    CLEAR_SCREEN.store(enabled, Ordering::Relaxed);
 }
 
 /// Clear the screen (works on most terminals)
 fn clear_screen() {
+    if !CLEAR_SCREEN.load(Ordering::Relaxed) {
+        // Keep scrollback intact, just mark where the screen would have cleared
+        println!("\n{}\n", "~".repeat(60));
+        return;
+    }
     print!("\x1B[2J\x1B[1;1H");
     io::stdout().flush().unwrap();
 }
 
+/// Title shown above the main menu
+pub const MAIN_BANNER: &str = concat!(crab!(), " RUST LECTURE DEMONSTRATION SYSTEM ", crab!());
+/// Header printed before running every section
+pub const RUN_ALL_BANNER: &str = concat!(crab!(), " RUNNING ALL LECTURE SECTIONS ", crab!());
+/// Header of the enhanced navigation menu
+pub const NAVIGATION_BANNER: &str = concat!(crab!(), " ENHANCED NAVIGATION MODE ", crab!());
+/// Header when navigating through every section's demos
+pub const ALL_NAVIGATION_BANNER: &str = concat!(crab!(), " ALL SECTIONS - ENHANCED NAVIGATION ", crab!());
+/// Footer after every section has run
+pub const ALL_COMPLETED_BANNER: &str = concat!(icon!("🎉", "**"), " ALL SECTIONS COMPLETED! ", icon!("🎉", "**"));
+/// Main menu heading for the section list
+pub const SECTIONS_HEADING: &str = concat!(icon!("📚", "[#]"), " LECTURE SECTIONS:");
+/// Main menu heading for the non-section commands
+pub const OPTIONS_HEADING: &str = concat!(icon!("🚀", "[>]"), " SPECIAL OPTIONS:");
+/// Main menu hint about enhanced navigation
+pub const ENHANCED_HINT: &str = concat!(icon!("💡", "(!)"), " Enhanced mode allows you to navigate individual demos with:");
+/// Label in front of the main menu progress counter
+pub const PROGRESS_LABEL: &str = concat!(icon!("📈", "[%]"), " Progress:");
+/// Suffix for sections already run in this session
+pub const DONE_MARK: &str = icon!(" ✓", " [x]");
+/// Help text shown after repeated invalid menu choices
+pub const MENU_HELP: &str = concat!(icon!("❓", "[?]"), " HELP: valid choices are 1-8 (run a section), 'all', 'e', 'step' or 'q'.");
+/// Key reference shown below each demo in enhanced navigation
+pub const NAVIGATION_KEYS: &str = concat!(icon!("⌨️ ", "[keys]"), " Navigation: [Enter]=Next ['p']=Previous ['f']=First ['l']=Last ['m']=Menu ['q']=Quit");
+/// Header of the `--dry-run` listing
+pub const DRY_RUN_HEADING: &str = concat!(icon!("🧪", "[dry-run]"), " DRY RUN - nothing will be executed");
+
 /// Run all sections sequentially
 pub fn run_all_sections() {
-    println!("🦀 RUNNING ALL LECTURE SECTIONS 🦀");
+    println!("{}", RUN_ALL_BANNER);
     println!("==================================");
     println!();
     
@@ -169,17 +252,30 @@ pub fn run_all_sections() {
     
     section8_crates::run_all_demos();
     
-    println!("\n🎉 ALL SECTIONS COMPLETED! 🎉");
+    println!("\n{}", ALL_COMPLETED_BANNER);
     println!("You've seen a comprehensive overview of Rust!");
 }
 
 
+/// Loop through every section unattended, for kiosk displays
+///
+/// Each section is followed by a `countdown_pause` of `secs` seconds that
+/// Enter skips early; stop the loop with Ctrl+C.
+pub fn run_kiosk_loop(secs: u64) {
+    loop {
+        for section in Section::ALL {
+            section.run_all();
+            lecture_utils::countdown_pause(&format!("Finished Section {}: {}", section.number(), section), secs);
+        }
+    }
+}
+
 /// Individual demo runners for fine-grained control during lectures
 pub mod individual_demos {
     use super::*;
     
-    /// Run a specific demo from section 1
-    pub fn run_section1_demo(demo_name: &str) {
+    /// Run a specific demo from section 1, returning `false` for an unknown key
+    pub fn run_section1_demo(demo_name: &str) -> bool {
         match demo_name {
             "hello" => section1_basics::demo_hello_world(),
             "variables" => section1_basics::demo_variables_mutability(),
@@ -189,12 +285,23 @@ pub mod individual_demos {
             "for" => section1_basics::demo_for_loops(),
             "while" => section1_basics::demo_while_loops(),
             "blocks" => section1_basics::demo_block_expressions(),
-            _ => println!("Unknown demo: {}", demo_name),
+            "format" => section1_basics::demo_formatting(),
+            "chars" => section1_basics::demo_chars(),
+            "recursion" => section1_basics::demo_recursion(),
+            "timing" => section1_basics::demo_timing(),
+            "operators" => section1_basics::demo_operators(),
+            "slicing" => section1_basics::demo_slicing(),
+            "shadowing" => section1_basics::demo_shadowing_basics(),
+            _ => {
+                println!("Unknown demo: {}", demo_name);
+                return false;
+            }
         }
+        true
     }
     
-    /// Run a specific demo from section 2
-    pub fn run_section2_demo(demo_name: &str) {
+    /// Run a specific demo from section 2, returning `false` for an unknown key
+    pub fn run_section2_demo(demo_name: &str) -> bool {
         match demo_name {
             "scope" => section2_ownership::demo_ownership_scope(),
             "move" => section2_ownership::demo_move_semantics(),
@@ -202,12 +309,18 @@ pub mod individual_demos {
             "functions" => section2_ownership::demo_function_ownership(),
             "collections" => section2_ownership::demo_collection_ownership(),
             "patterns" => section2_ownership::demo_ownership_patterns(),
-            _ => println!("Unknown demo: {}", demo_name),
+            "partial_move" => section2_ownership::demo_partial_move(),
+            "clone_cost" => section2_ownership::demo_clone_cost(),
+            _ => {
+                println!("Unknown demo: {}", demo_name);
+                return false;
+            }
         }
+        true
     }
     
-    /// Run a specific demo from section 3
-    pub fn run_section3_demo(demo_name: &str) {
+    /// Run a specific demo from section 3, returning `false` for an unknown key
+    pub fn run_section3_demo(demo_name: &str) -> bool {
         match demo_name {
             "immutable" => section3_borrowing::demo_immutable_borrowing(),
             "mutable" => section3_borrowing::demo_mutable_borrowing(),
@@ -216,12 +329,20 @@ pub mod individual_demos {
             "elision" => section3_borrowing::demo_lifetime_elision(),
             "patterns" => section3_borrowing::demo_reference_patterns(),
             "dangling" => section3_borrowing::demo_dangling_prevention(),
-            _ => println!("Unknown demo: {}", demo_name),
+            "cell" => section3_borrowing::demo_cell(),
+            "lifetime_bounds" => section3_borrowing::demo_lifetime_bounds(),
+            "lifetime_methods" => section3_borrowing::demo_lifetime_methods(),
+            "static" => section3_borrowing::demo_static_lifetime(),
+            _ => {
+                println!("Unknown demo: {}", demo_name);
+                return false;
+            }
         }
+        true
     }
     
-    /// Run a specific demo from section 4
-    pub fn run_section4_demo(demo_name: &str) {
+    /// Run a specific demo from section 4, returning `false` for an unknown key
+    pub fn run_section4_demo(demo_name: &str) -> bool {
         match demo_name {
             "basic" => section4_traits::demo_basic_traits(),
             "generics" => section4_traits::demo_generic_functions(),
@@ -230,12 +351,24 @@ pub mod individual_demos {
             "associated" => section4_traits::demo_associated_types(),
             "operators" => section4_traits::demo_operator_overloading(),
             "standard" => section4_traits::demo_standard_traits(),
-            _ => println!("Unknown demo: {}", demo_name),
+            "generic_methods" => section4_traits::demo_generic_methods(),
+            "object_safety" => section4_traits::demo_object_safety(),
+            "custom_ord" => section4_traits::demo_custom_ord(),
+            "more_operators" => section4_traits::demo_more_operators(),
+            "generic_ordering" => section4_traits::demo_generic_ordering(),
+            "phantom" => section4_traits::demo_phantom_data(),
+            "downcast" => section4_traits::demo_any_downcast(),
+            "memoize" => section4_traits::demo_memoize(),
+            _ => {
+                println!("Unknown demo: {}", demo_name);
+                return false;
+            }
         }
+        true
     }
     
-    /// Run a specific demo from section 5
-    pub fn run_section5_demo(demo_name: &str) {
+    /// Run a specific demo from section 5, returning `false` for an unknown key
+    pub fn run_section5_demo(demo_name: &str) -> bool {
         match demo_name {
             "basic" => section5_enums::demo_basic_enums(),
             "data" => section5_enums::demo_enums_with_data(),
@@ -244,12 +377,22 @@ pub mod individual_demos {
             "patterns" => section5_enums::demo_advanced_patterns(),
             "recursive" => section5_enums::demo_recursive_enums(),
             "propagation" => section5_enums::demo_error_propagation(),
-            _ => println!("Unknown demo: {}", demo_name),
+            "res_combinators" => section5_enums::demo_result_combinators(),
+            "main_q" => section5_enums::demo_main_question_mark(),
+            "non_exhaustive" => section5_enums::demo_non_exhaustive(),
+            "state_machine" => section5_enums::demo_state_machine(),
+            "tagged_json" => section5_enums::demo_tagged_json(),
+            "error_boxing" => section5_enums::demo_error_boxing(),
+            _ => {
+                println!("Unknown demo: {}", demo_name);
+                return false;
+            }
         }
+        true
     }
     
-    /// Run a specific demo from section 6
-    pub fn run_section6_demo(demo_name: &str) {
+    /// Run a specific demo from section 6, returning `false` for an unknown key
+    pub fn run_section6_demo(demo_name: &str) -> bool {
         match demo_name {
             "iterators" => section6_idioms::demo_iterator_patterns(),
             "advanced_iterators" => section6_idioms::demo_advanced_iterators(),
@@ -257,12 +400,30 @@ pub mod individual_demos {
             "shadowing" => section6_idioms::demo_shadowing_patterns(),
             "memory" => section6_idioms::demo_memory_patterns(),
             "utilities" => section6_idioms::demo_utility_patterns(),
-            _ => println!("Unknown demo: {}", demo_name),
+            "combining" => section6_idioms::demo_iterator_combining(),
+            "cow_normalize" => section6_idioms::demo_cow_normalize(),
+            "sealed" => section6_idioms::demo_sealed_extension(),
+            "splitting" => section6_idioms::demo_string_splitting(),
+            "entry_api" => section6_idioms::demo_entry_api(),
+            "closures" => section6_idioms::demo_closures(),
+            "boxed_closures" => section6_idioms::demo_boxed_closures(),
+            "aggregations" => section6_idioms::demo_aggregations(),
+            "vec_mutation" => section6_idioms::demo_vec_mutation(),
+            "vecdeque" => section6_idioms::demo_vecdeque(),
+            "btree_nav" => section6_idioms::demo_btreemap_navigation(),
+            "string_conversions" => section6_idioms::demo_string_conversions(),
+            "try_fold" => section6_idioms::demo_try_fold(),
+            "collect_targets" => section6_idioms::demo_collect_targets(),
+            _ => {
+                println!("Unknown demo: {}", demo_name);
+                return false;
+            }
         }
+        true
     }
     
-    /// Run a specific demo from section 7
-    pub fn run_section7_demo(demo_name: &str) {
+    /// Run a specific demo from section 7, returning `false` for an unknown key
+    pub fn run_section7_demo(demo_name: &str) -> bool {
         match demo_name {
             "threading" => section7_concurrency::demo_basic_threading(),
             "channels" => section7_concurrency::demo_message_passing(),
@@ -270,12 +431,29 @@ pub mod individual_demos {
             "advanced" => section7_concurrency::demo_advanced_concurrency(),
             "async" => section7_concurrency::demo_async_basics(),
             "safety" => section7_concurrency::demo_thread_safety(),
-            _ => println!("Unknown demo: {}", demo_name),
+            "barrier" => section7_concurrency::demo_barrier(),
+            "real_async" => section7_concurrency::demo_real_async(),
+            "deadlock" => section7_concurrency::demo_deadlock_avoidance(),
+            "worker_pool" => section7_concurrency::demo_worker_pool(),
+            "recv_timeout" => section7_concurrency::demo_recv_timeout(),
+            "crossbeam_scope" => section7_concurrency::demo_crossbeam_scope(),
+            "shutdown" => section7_concurrency::demo_graceful_shutdown(),
+            "pipeline" => section7_concurrency::demo_pipeline(),
+            "once" => section7_concurrency::demo_once(),
+            "thread_panic" => section7_concurrency::demo_thread_panic(),
+            "channel_bench" => section7_concurrency::demo_channel_benchmark(),
+            "send_sync" => section7_concurrency::demo_send_sync_markers(),
+            "cancellation" => section7_concurrency::demo_cancellation_token(),
+            _ => {
+                println!("Unknown demo: {}", demo_name);
+                return false;
+            }
         }
+        true
     }
     
-    /// Run a specific demo from section 8
-    pub fn run_section8_demo(demo_name: &str) {
+    /// Run a specific demo from section 8, returning `false` for an unknown key
+    pub fn run_section8_demo(demo_name: &str) -> bool {
         match demo_name {
             "1" | "serde" => section8_crates::demo_1_serde_json(),
             "2" | "rand" => section8_crates::demo_2_rand(),
@@ -297,8 +475,20 @@ pub mod individual_demos {
             "18" | "bitflags" => section8_crates::demo_18_bitflags(),
             "19" | "parking_lot" => section8_crates::demo_19_parking_lot(),
             "20" | "collections" => section8_crates::demo_20_advanced_collections(),
-            _ => println!("Unknown demo: {}", demo_name),
+            "21" | "csv" => section8_crates::demo_21_csv(),
+            "22" | "toml" => section8_crates::demo_22_toml(),
+            "23" | "indicatif" => section8_crates::demo_23_indicatif(),
+            "24" | "process" => section8_crates::demo_24_process(),
+            "25" | "filesystem" => section8_crates::demo_25_filesystem(),
+            "26" | "env" => section8_crates::demo_26_env(),
+            "27" | "base64" => section8_crates::demo_27_base64(),
+            "28" | "hashing" => section8_crates::demo_28_hashing(),
+            _ => {
+                println!("Unknown demo: {}", demo_name);
+                return false;
+            }
         }
+        true
     }
     
     /// Print available demos for a section
@@ -306,31 +496,41 @@ pub mod individual_demos {
         match section {
             1 => {
                 println!("Available Section 1 demos:");
-                println!("  hello, variables, functions, if, match, for, while, blocks");
+                println!("  hello, variables, functions, if, match, for, while, blocks, format, chars,");
+                println!("  recursion, timing, operators, slicing, shadowing");
             }
             2 => {
                 println!("Available Section 2 demos:");
-                println!("  scope, move, copy, functions, collections, patterns");
+                println!("  scope, move, copy, functions, collections, patterns, partial_move, clone_cost");
             }
             3 => {
                 println!("Available Section 3 demos:");
-                println!("  immutable, mutable, rules, lifetimes, elision, patterns, dangling");
+                println!("  immutable, mutable, rules, lifetimes, elision, patterns, dangling, cell,");
+                println!("  lifetime_bounds, lifetime_methods, static");
             }
             4 => {
                 println!("Available Section 4 demos:");
-                println!("  basic, generics, objects, structs, associated, operators, standard");
+                println!("  basic, generics, objects, structs, associated, operators, standard, generic_methods,");
+                println!("  object_safety, custom_ord, more_operators, generic_ordering, phantom, downcast,");
+                println!("  memoize");
             }
             5 => {
                 println!("Available Section 5 demos:");
-                println!("  basic, data, option, result, patterns, recursive, propagation");
+                println!("  basic, data, option, result, patterns, recursive, propagation, res_combinators,");
+                println!("  main_q, non_exhaustive, state_machine, tagged_json, error_boxing");
             }
             6 => {
                 println!("Available Section 6 demos:");
-                println!("  iterators, advanced_iterators, errors, shadowing, memory, utilities");
+                println!("  iterators, advanced_iterators, errors, shadowing, memory, utilities, combining,");
+                println!("  cow_normalize, sealed, splitting, entry_api, closures, boxed_closures,");
+                println!("  aggregations, vec_mutation, vecdeque, btree_nav, string_conversions, try_fold,");
+                println!("  collect_targets");
             }
             7 => {
                 println!("Available Section 7 demos:");
-                println!("  threading, channels, shared, advanced, async, safety");
+                println!("  threading, channels, shared, advanced, async, safety, barrier, real_async, deadlock, worker_pool,");
+                println!("  recv_timeout, crossbeam_scope, shutdown, pipeline, once, thread_panic,");
+                println!("  channel_bench, send_sync, cancellation");
             }
             8 => {
                 println!("Available Section 8 demos:");
@@ -354,6 +554,14 @@ pub mod individual_demos {
                 println!("  18/bitflags - Bit flag operations");
                 println!("  19/parking_lot - High-performance synchronization");
                 println!("  20/collections - Advanced collection types");
+                println!("  21/csv - CSV parsing with serde");
+                println!("  22/toml - Typed configuration files with serde");
+                println!("  23/indicatif - Progress Bars and Spinners");
+                println!("  24/process - Running External Commands");
+                println!("  25/filesystem - Recursive Directory Walking");
+                println!("  26/env - Configuration from Environment Variables");
+                println!("  27/base64 - Encoding Binary Data as Text");
+                println!("  28/hashing - SHA-256 Digests for Integrity and Caching");
             }
             _ => println!("Invalid section number. Use 1-8."),
         }
@@ -362,32 +570,188 @@ pub mod individual_demos {
 
 /// Utility functions for lecture management
 pub mod lecture_utils {
+    use std::sync::atomic::{AtomicBool, Ordering};
+    use std::sync::mpsc::{self, Receiver};
+    use std::sync::{Mutex, OnceLock};
+    use std::thread;
+    
+    /// Runtime color switch; only has an effect with the `color` feature
+    static COLOR: AtomicBool = AtomicBool::new(true);
+    
+    /// Whether step() pauses; toggled with the `step` main-menu command
+    static STEP_MODE: AtomicBool = AtomicBool::new(false);
+    
+    /// Enable or disable colored separators and concept highlights
+    pub fn set_color(enabled: bool) {
+        COLOR.store(enabled, Ordering::Relaxed);
+    }
+    
+    /// Wrap `text` in an ANSI color code, unless color is compiled out,
+    /// switched off, or the user set `NO_COLOR` (https://no-color.org)
+    fn paint(text: &str, code: &str) -> String {
+        let no_color = std::env::var_os("NO_COLOR").is_some_and(|v| !v.is_empty());
+        if cfg!(feature = "color") && COLOR.load(Ordering::Relaxed) && !no_color {
+            format!("\x1B[{}m{}\x1B[0m", code, text)
+        } else {
+            text.to_string()
+        }
+    }
+    
+    /// Width of section separators and recap boxes
+    pub const SEPARATOR_WIDTH: usize = 60;
+    
+    /// Markers in front of demo titles and navigation messages
+    pub const DEMO_MARKER: &str = icon!("📍", ">>");
+    /// Marker for lecture pauses
+    pub const PAUSE_MARKER: &str = icon!("⏸️ ", "||");
+    /// Marker for step-mode breaks
+    pub const STEP_MARKER: &str = icon!("⏭️ ", ">|");
+    /// Marker for highlighted concepts
+    pub const CONCEPT_MARKER: &str = icon!("💡", "(!)");
+    /// Marker for completion messages
+    pub const CELEBRATE_MARKER: &str = icon!("🎉", "**");
+    /// Marker for custom demo sequences
+    pub const SEQUENCE_MARKER: &str = icon!("🎯", ">>");
+    
     /// Print a section separator
     pub fn print_section_separator(section_name: &str) {
-        let separator = "=".repeat(60);
+        let separator = paint(&"=".repeat(SEPARATOR_WIDTH), "36");
         println!("\n{}", separator);
-        println!("🦀 {}", section_name.to_uppercase());
+        println!("{}", paint(&format!(concat!(crab!(), " {}"), section_name.to_uppercase()), "1;36"));
         println!("{}\n", separator);
     }
     
     /// Print a demo separator
     pub fn print_demo_separator(demo_name: &str) {
-        println!("\n{}", "-".repeat(40));
-        println!("📍 {}", demo_name);
-        println!("{}", "-".repeat(40));
+        let separator = paint(&"-".repeat(40), "33");
+        println!("\n{}", separator);
+        println!("{}", paint(&format!("{} {}", DEMO_MARKER, demo_name), "1;33"));
+        println!("{}", separator);
     }
     
     /// Create a pause for live demonstrations
     pub fn lecture_pause(message: &str) {
-        println!("\n⏸️  LECTURE PAUSE: {}", message);
+        println!("\n{} LECTURE PAUSE: {}", PAUSE_MARKER, message);
         println!("   Press Enter when ready to continue...");
-        let mut input = String::new();
-        std::io::stdin().read_line(&mut input).unwrap();
+        read_line();
+    }
+    
+    /// Lines typed on stdin, fed by one long-lived reader thread started on first use
+    ///
+    /// Every prompt reads through this channel, so a line typed after a
+    /// countdown_pause timed out reaches the next prompt instead of being lost.
+    fn stdin_lines() -> &'static Mutex<Receiver<String>> {
+        static LINES: OnceLock<Mutex<Receiver<String>>> = OnceLock::new();
+        LINES.get_or_init(|| {
+            let (tx, rx) = mpsc::channel();
+            thread::spawn(move || {
+                for line in std::io::stdin().lines() {
+                    let Ok(line) = line else { break };
+                    if tx.send(line).is_err() {
+                        break;
+                    }
+                }
+            });
+            Mutex::new(rx)
+        })
+    }
+    
+    /// Read one line of input; empty once stdin is closed
+    pub fn read_line() -> String {
+        stdin_lines().lock().unwrap().recv().unwrap_or_default()
+    }
+    
+    /// Pause until Enter is pressed or `secs` seconds pass, for unattended runs
+    pub fn countdown_pause(message: &str, secs: u64) {
+        use std::io::Write;
+        use std::sync::mpsc::RecvTimeoutError;
+        use std::time::Duration;
+        
+        println!("\n{} LECTURE PAUSE: {}", PAUSE_MARKER, message);
+        
+        let lines = stdin_lines().lock().unwrap();
+        for remaining in (1..=secs).rev() {
+            print!("\r   Press Enter to continue, auto-continuing in {}s... ", remaining);
+            std::io::stdout().flush().unwrap();
+            match lines.recv_timeout(Duration::from_secs(1)) {
+                Ok(_) => break,
+                Err(RecvTimeoutError::Timeout) => {}
+                // stdin closed (e.g. piped input ran out): keep counting down
+                Err(RecvTimeoutError::Disconnected) => thread::sleep(Duration::from_secs(1)),
+            }
+        }
+        println!();
+    }
+    
+    /// Lines of the boxed, bulleted recap printed by `print_recap`
+    pub fn recap_lines(section_name: &str, points: &[&str]) -> Vec<String> {
+        let (horizontal, vertical, bullet) = (icon!("─", "-"), icon!("│", "|"), icon!("•", "*"));
+        let width = SEPARATOR_WIDTH - 3;
+        let border = |left: &str, right: &str| format!("{}{}{}", left, horizontal.repeat(SEPARATOR_WIDTH - 2), right);
+        
+        // Word-wrap `text` into padded rows; continuation rows start with `indent`
+        let rows = |text: &str, indent: &str| {
+            let mut wrapped = Vec::new();
+            let mut current = String::new();
+            let mut has_words = false;
+            for word in text.split_whitespace() {
+                if has_words && current.chars().count() + 1 + word.chars().count() > width {
+                    wrapped.push(std::mem::take(&mut current));
+                    current.push_str(indent);
+                    has_words = false;
+                }
+                if has_words {
+                    current.push(' ');
+                }
+                current.push_str(word);
+                has_words = true;
+            }
+            wrapped.push(current);
+            wrapped.into_iter()
+                .map(|line| format!("{} {:<width$}{}", vertical, line, vertical))
+                .collect::<Vec<_>>()
+        };
+        
+        let mut lines = vec![border(icon!("┌", "+"), icon!("┐", "+"))];
+        lines.extend(rows(&format!("RECAP: {}", section_name), "       "));
+        lines.push(border(icon!("├", "+"), icon!("┤", "+")));
+        for point in points {
+            lines.extend(rows(&format!("{} {}", bullet, point), "  "));
+        }
+        lines.push(border(icon!("└", "+"), icon!("┘", "+")));
+        lines
+    }
+    
+    /// Print a boxed, bulleted recap of the concepts a section covered
+    pub fn print_recap(section_name: &str, points: &[&str]) {
+        for line in recap_lines(section_name, points) {
+            println!("{}", line);
+        }
+        println!();
+    }
+    
+    /// Enable or disable pausing at step() boundaries inside demos
+    pub fn set_step_mode(enabled: bool) {
+        STEP_MODE.store(enabled, Ordering::Relaxed);
+    }
+    
+    /// Whether step mode is currently on
+    pub fn step_mode() -> bool {
+        STEP_MODE.load(Ordering::Relaxed)
+    }
+    
+    /// Pause for Enter at a natural break inside a demo, only in step mode
+    pub fn step(message: &str) {
+        if !step_mode() {
+            return;
+        }
+        println!("\n{} {} (press Enter)", STEP_MARKER, message);
+        read_line();
     }
     
     /// Highlight important concepts
     pub fn highlight_concept(concept: &str, explanation: &str) {
-        println!("\n💡 KEY CONCEPT: {}", concept);
+        println!("\n{}", paint(&format!("{} KEY CONCEPT: {}", CONCEPT_MARKER, concept), "1;32"));
         println!("   {}", explanation);
         println!();
     }
@@ -395,58 +759,62 @@ pub mod lecture_utils {
 
 /// Enhanced navigation mode for individual demo control
 pub fn run_enhanced_navigation_mode() {
-    println!("🦀 ENHANCED NAVIGATION MODE 🦀");
-    println!("==============================");
-    println!();
-    println!("Choose a section to navigate through individual demos:");
-    println!("  1. Basic Syntax and Constructs (8 demos)");
-    println!("  2. Ownership and Move Semantics (6 demos)");
-    println!("  3. Borrowing, References, and Lifetimes (7 demos)");
-    println!("  4. Trait System and Generics (7 demos)");
-    println!("  5. Enums, Pattern Matching, Option & Result (7 demos)");
-    println!("  6. Idiomatic Patterns & Utilities (6 demos)");
-    println!("  7. Fearless Concurrency (6 demos)");
-    println!("  8. Popular Crate Examples (20 demos)");
-    println!("  all - Navigate through all demos sequentially");
-    println!("  q   - Return to main menu");
-    println!();
-    
-    let choice = get_user_input("Enter your choice: ");
-    
-    match choice.trim() {
-        "1" => run_section_enhanced_navigation(1),
-        "2" => run_section_enhanced_navigation(2),
-        "3" => run_section_enhanced_navigation(3),
-        "4" => run_section_enhanced_navigation(4),
-        "5" => run_section_enhanced_navigation(5),
-        "6" => run_section_enhanced_navigation(6),
-        "7" => run_section_enhanced_navigation(7),
-        "8" => run_section_enhanced_navigation(8),
-        "all" | "ALL" => run_all_demos_enhanced_navigation(),
-        "q" | "Q" => return,
-        _ => {
-            println!("Invalid choice. Returning to main menu.");
-            wait_for_enter();
+    loop {
+        println!("{}", NAVIGATION_BANNER);
+        println!("==============================");
+        println!();
+        println!("Choose a section to navigate through individual demos:");
+        for section in Section::ALL {
+            println!("  {}. {} ({} demos)", section.number(), section, section.demos().len());
+        }
+        println!("  all - Navigate through all demos sequentially");
+        println!("  q   - Return to main menu");
+        println!();
+        
+        let choice = get_user_input("Enter your choice: ");
+        
+        let back_to_menu = match choice.trim() {
+            "all" | "ALL" => run_all_demos_enhanced_navigation(),
+            "q" | "Q" => return,
+            other => match other.parse::<u8>().ok().and_then(|n| Section::try_from(n).ok()) {
+                Some(section) => run_section_enhanced_navigation(section),
+                None => {
+                    println!("Invalid choice. Returning to main menu.");
+                    wait_for_enter();
+                    false
+                }
+            },
+        };
+        
+        if !back_to_menu {
+            return;
         }
+        clear_screen();
     }
 }
 
 /// Run enhanced navigation for a specific section
-fn run_section_enhanced_navigation(section: u8) {
-    let demos = get_section_demo_list(section);
+///
+/// Returns `true` if the user asked to go back to the section-selection menu.
+fn run_section_enhanced_navigation(section: Section) -> bool {
+    let demos = section.demos();
     if demos.is_empty() {
-        println!("No demos found for section {}", section);
+        println!("No demos found for section {}", section.number());
         wait_for_enter();
-        return;
+        return false;
     }
     
     clear_screen();
-    println!("🦀 SECTION {} - ENHANCED NAVIGATION 🦀", section);
+    println!(concat!(crab!(), " SECTION {} - ENHANCED NAVIGATION ", crab!()), section.number());
+    println!("{}", section);
     println!("=====================================");
     println!();
     println!("Controls:");
     println!("  Enter = Next demo");
     println!("  'p'   = Previous demo");
+    println!("  'f'   = First demo");
+    println!("  'l'   = Last demo");
+    println!("  'm'   = Back to section menu");
     println!("  'q'   = Quit to main menu");
     println!();
     
@@ -454,23 +822,24 @@ fn run_section_enhanced_navigation(section: u8) {
     
     loop {
         // Run the current demo
-        run_individual_demo(section, demos[current_index]);
+        section.run_demo(demos[current_index]);
         
         // Get navigation input
-        println!("\n⌨️  Navigation: [Enter]=Next ['p']=Previous ['q']=Quit");
+        println!("\n{}", NAVIGATION_KEYS);
         match get_enhanced_navigation_input() {
             NavigationAction::Next => {
                 if current_index < demos.len() - 1 {
                     current_index += 1;
                     clear_screen();
                 } else {
-                    println!("\n🎉 You've reached the end of Section {}!", section);
+                    println!("\n{} You've reached the end of Section {}!", lecture_utils::CELEBRATE_MARKER, section.number());
                     println!("Press Enter to return to menu, or 'p' to go back.");
                     match get_enhanced_navigation_input() {
                         NavigationAction::Previous => {
                             current_index = demos.len() - 1;
                             clear_screen();
                         }
+                        NavigationAction::Menu => return true,
                         _ => break,
                     }
                 }
@@ -480,27 +849,39 @@ fn run_section_enhanced_navigation(section: u8) {
                     current_index -= 1;
                     clear_screen();
                 } else {
-                    println!("\n📍 You're at the beginning of Section {}!", section);
+                    println!("\n{} You're at the beginning of Section {}!", lecture_utils::DEMO_MARKER, section.number());
                     println!("Press Enter to continue, or 'q' to quit.");
                     match get_enhanced_navigation_input() {
+                        NavigationAction::Menu => return true,
                         NavigationAction::Quit => break,
                         _ => clear_screen(),
                     }
                 }
             }
+            NavigationAction::First => {
+                current_index = 0;
+                clear_screen();
+            }
+            NavigationAction::Last => {
+                current_index = demos.len() - 1;
+                clear_screen();
+            }
+            NavigationAction::Menu => return true,
             NavigationAction::Quit => break,
         }
     }
+    false
 }
 
 /// Run all demos with enhanced navigation
-fn run_all_demos_enhanced_navigation() {
+///
+/// Returns `true` if the user asked to go back to the section-selection menu.
+fn run_all_demos_enhanced_navigation() -> bool {
     let mut all_demos = Vec::new();
     
     // Collect all demos from all sections
-    for section in 1..=8 {
-        let section_demos = get_section_demo_list(section);
-        for demo_name in section_demos {
+    for section in Section::ALL {
+        for demo_name in section.demos() {
             all_demos.push((section, demo_name));
         }
     }
@@ -508,16 +889,19 @@ fn run_all_demos_enhanced_navigation() {
     if all_demos.is_empty() {
         println!("No demos found!");
         wait_for_enter();
-        return;
+        return false;
     }
     
     clear_screen();
-    println!("🦀 ALL SECTIONS - ENHANCED NAVIGATION 🦀");
+    println!("{}", ALL_NAVIGATION_BANNER);
     println!("========================================");
     println!();
     println!("Controls:");
     println!("  Enter = Next demo");
     println!("  'p'   = Previous demo");
+    println!("  'f'   = First demo");
+    println!("  'l'   = Last demo");
+    println!("  'm'   = Back to section menu");
     println!("  'q'   = Quit to main menu");
     println!();
     
@@ -525,24 +909,25 @@ fn run_all_demos_enhanced_navigation() {
     
     loop {
         // Run the current demo
-        let (section, demo_name) = &all_demos[current_index];
-        run_individual_demo(*section, demo_name);
+        let (section, demo_name) = all_demos[current_index];
+        section.run_demo(demo_name);
         
         // Get navigation input
-        println!("\n⌨️  Navigation: [Enter]=Next ['p']=Previous ['q']=Quit");
+        println!("\n{}", NAVIGATION_KEYS);
         match get_enhanced_navigation_input() {
             NavigationAction::Next => {
                 if current_index < all_demos.len() - 1 {
                     current_index += 1;
                     clear_screen();
                 } else {
-                    println!("\n🎉 You've completed all demos!");
+                    println!("\n{} You've completed all demos!", lecture_utils::CELEBRATE_MARKER);
                     println!("Press Enter to return to menu, or 'p' to go back.");
                     match get_enhanced_navigation_input() {
                         NavigationAction::Previous => {
                             current_index = all_demos.len() - 1;
                             clear_screen();
                         }
+                        NavigationAction::Menu => return true,
                         _ => break,
                     }
                 }
@@ -552,17 +937,28 @@ fn run_all_demos_enhanced_navigation() {
                     current_index -= 1;
                     clear_screen();
                 } else {
-                    println!("\n📍 You're at the beginning!");
+                    println!("\n{} You're at the beginning!", lecture_utils::DEMO_MARKER);
                     println!("Press Enter to continue, or 'q' to quit.");
                     match get_enhanced_navigation_input() {
+                        NavigationAction::Menu => return true,
                         NavigationAction::Quit => break,
                         _ => clear_screen(),
                     }
                 }
             }
+            NavigationAction::First => {
+                current_index = 0;
+                clear_screen();
+            }
+            NavigationAction::Last => {
+                current_index = all_demos.len() - 1;
+                clear_screen();
+            }
+            NavigationAction::Menu => return true,
             NavigationAction::Quit => break,
         }
     }
+    false
 }
 
 /// Navigation actions for enhanced mode
@@ -570,6 +966,9 @@ fn run_all_demos_enhanced_navigation() {
 enum NavigationAction {
     Next,
     Previous,
+    First,
+    Last,
+    Menu,
     Quit,
 }
 
@@ -578,43 +977,312 @@ fn get_enhanced_navigation_input() -> NavigationAction {
     print!("Your choice: ");
     io::stdout().flush().unwrap();
     
-    let mut input = String::new();
-    io::stdin().read_line(&mut input).unwrap();
+    let input = lecture_utils::read_line();
     
     match input.trim() {
         "" => NavigationAction::Next,  // Enter key
         "q" | "Q" | "quit" => NavigationAction::Quit,
         "p" | "P" | "prev" | "previous" => NavigationAction::Previous,
+        "f" | "F" | "first" => NavigationAction::First,
+        "l" | "L" | "last" => NavigationAction::Last,
+        "m" | "M" | "menu" => NavigationAction::Menu,
         _ => NavigationAction::Next,  // Default to next for any other input
     }
 }
 
-/// Get demo list for a section
-fn get_section_demo_list(section: u8) -> Vec<&'static str> {
-    match section {
-        1 => section1_basics::get_demo_list(),
-        2 => section2_ownership::get_demo_list(),
-        3 => section3_borrowing::get_demo_list(),
-        4 => section4_traits::get_demo_list(),
-        5 => section5_enums::get_demo_list(),
-        6 => section6_idioms::get_demo_list(),
-        7 => section7_concurrency::get_demo_list(),
-        8 => section8_crates::get_demo_list(),
-        _ => vec![],
+/// The eight lecture sections, numbered 1-8 in lecture order
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Section {
+    Basics = 1,
+    Ownership,
+    Borrowing,
+    Traits,
+    Enums,
+    Idioms,
+    Concurrency,
+    Crates,
+}
+
+impl Section {
+    /// All sections in lecture order
+    pub const ALL: [Section; 8] = [
+        Section::Basics,
+        Section::Ownership,
+        Section::Borrowing,
+        Section::Traits,
+        Section::Enums,
+        Section::Idioms,
+        Section::Concurrency,
+        Section::Crates,
+    ];
+    
+    /// The section number used on the command line and in menus
+    pub fn number(self) -> u8 {
+        self as u8
+    }
+    
+    /// Demo keys for enhanced navigation, in lecture order
+    pub fn demos(self) -> Vec<&'static str> {
+        match self {
+            Section::Basics => section1_basics::get_demo_list(),
+            Section::Ownership => section2_ownership::get_demo_list(),
+            Section::Borrowing => section3_borrowing::get_demo_list(),
+            Section::Traits => section4_traits::get_demo_list(),
+            Section::Enums => section5_enums::get_demo_list(),
+            Section::Idioms => section6_idioms::get_demo_list(),
+            Section::Concurrency => section7_concurrency::get_demo_list(),
+            Section::Crates => section8_crates::get_demo_list(),
+        }
+    }
+    
+    /// Run every demo in this section, with its banner and recap
+    pub fn run_all(self) {
+        match self {
+            Section::Basics => section1_basics::run_all_demos(),
+            Section::Ownership => section2_ownership::run_all_demos(),
+            Section::Borrowing => section3_borrowing::run_all_demos(),
+            Section::Traits => section4_traits::run_all_demos(),
+            Section::Enums => section5_enums::run_all_demos(),
+            Section::Idioms => section6_idioms::run_all_demos(),
+            Section::Concurrency => section7_concurrency::run_all_demos(),
+            Section::Crates => section8_crates::run_all_demos(),
+        }
+    }
+    
+    /// Run a single demo from this section by key; `false` if the key is unknown
+    pub fn run_demo(self, demo_name: &str) -> bool {
+        match self {
+            Section::Basics => individual_demos::run_section1_demo(demo_name),
+            Section::Ownership => individual_demos::run_section2_demo(demo_name),
+            Section::Borrowing => individual_demos::run_section3_demo(demo_name),
+            Section::Traits => individual_demos::run_section4_demo(demo_name),
+            Section::Enums => individual_demos::run_section5_demo(demo_name),
+            Section::Idioms => individual_demos::run_section6_demo(demo_name),
+            Section::Concurrency => individual_demos::run_section7_demo(demo_name),
+            Section::Crates => individual_demos::run_section8_demo(demo_name),
+        }
     }
 }
 
-/// Run an individual demo
-fn run_individual_demo(section: u8, demo_name: &str) {
-    match section {
-        1 => individual_demos::run_section1_demo(demo_name),
-        2 => individual_demos::run_section2_demo(demo_name),
-        3 => individual_demos::run_section3_demo(demo_name),
-        4 => individual_demos::run_section4_demo(demo_name),
-        5 => individual_demos::run_section5_demo(demo_name),
-        6 => individual_demos::run_section6_demo(demo_name),
-        7 => individual_demos::run_section7_demo(demo_name),
-        8 => individual_demos::run_section8_demo(demo_name),
-        _ => println!("Unknown section: {}", section),
-    }
-}
\ No newline at end of file
+impl fmt::Display for Section {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let title = match self {
+            Section::Basics => "Basic Syntax and Constructs",
+            Section::Ownership => "Ownership and Move Semantics",
+            Section::Borrowing => "Borrowing, References, and Lifetimes",
+            Section::Traits => "Trait System and Generics",
+            Section::Enums => "Enums, Pattern Matching, Option & Result",
+            Section::Idioms => "Idiomatic Patterns & Utilities",
+            Section::Concurrency => "Fearless Concurrency",
+            Section::Crates => "Popular Crate Examples",
+        };
+        write!(f, "{}", title)
+    }
+}
+
+impl TryFrom<u8> for Section {
+    type Error = String;
+    
+    fn try_from(number: u8) -> Result<Self, Self::Error> {
+        Section::ALL
+            .get((number as usize).wrapping_sub(1))
+            .copied()
+            .ok_or_else(|| format!("Unknown section: {} (use 1-8)", number))
+    }
+}
+
+/// Run an individual demo, returning `false` if the section has no such key
+pub fn run_individual_demo(section: Section, demo_name: &str) -> bool {
+    section.run_demo(demo_name)
+}
+
+/// Metadata describing a single demo
+#[derive(Debug, Clone, Serialize)]
+pub struct DemoInfo {
+    pub section: u8,
+    pub key: &'static str,
+    pub title: &'static str,
+    pub description: &'static str,
+    /// Measured execution time in seconds, excluding network latency
+    pub est_secs: f32,
+}
+
+impl DemoInfo {
+    const fn new(
+        section: u8,
+        key: &'static str,
+        title: &'static str,
+        description: &'static str,
+        est_secs: f32,
+    ) -> Self {
+        DemoInfo { section, key, title, description, est_secs }
+    }
+}
+
+/// Catalog of every demo in lecture order
+///
+/// `key` is the name accepted by the matching `run_sectionN_demo` function.
+pub fn all_demos() -> Vec<DemoInfo> {
+    vec![
+        DemoInfo::new(1, "hello", "Hello World", "The traditional first program", 0.0),
+        DemoInfo::new(1, "variables", "Variables and Mutability", "Rust's default immutability", 0.0),
+        DemoInfo::new(1, "functions", "Functions", "Implicit returns and type annotations", 0.0),
+        DemoInfo::new(1, "if", "If Expressions", "if as an expression that returns values", 0.0),
+        DemoInfo::new(1, "match", "Match Expressions", "Rust's powerful pattern matching", 0.0),
+        DemoInfo::new(1, "for", "For Loops", "Iterating over ranges and collections", 0.0),
+        DemoInfo::new(1, "while", "While Loops", "Conditional iteration", 0.0),
+        DemoInfo::new(1, "blocks", "Block Expressions", "Blocks that return values", 0.0),
+        DemoInfo::new(1, "format", "String Formatting", "Width, precision, alignment and radixes", 0.0),
+        DemoInfo::new(1, "chars", "Chars and Unicode", "A char is a Unicode scalar, not a byte", 0.0),
+        DemoInfo::new(1, "recursion", "Recursion", "Base cases and the call stack", 0.0),
+        DemoInfo::new(1, "timing", "Timing", "Measuring and pausing with std::time", 0.1),
+        DemoInfo::new(1, "operators", "Operators", "Arithmetic, comparison, boolean and bitwise", 0.0),
+        DemoInfo::new(1, "slicing", "Slicing and Indexing", "Arrays, ranges and safe access", 0.0),
+        DemoInfo::new(1, "shadowing", "Shadowing", "Rebinding a name vs mutating a variable", 0.0),
+        DemoInfo::new(2, "scope", "Ownership and Scope", "Variables are dropped when they go out of scope", 0.0),
+        DemoInfo::new(2, "move", "Move Semantics", "Only one owner at a time for heap data", 0.0),
+        DemoInfo::new(2, "copy", "Copy Types", "Some types implement Copy trait for automatic copying", 0.0),
+        DemoInfo::new(2, "functions", "Function Ownership Transfer", "Functions can take ownership", 0.0),
+        DemoInfo::new(2, "collections", "Ownership with Collections", "Demonstrating moves in collections", 0.0),
+        DemoInfo::new(2, "patterns", "Common Ownership Patterns", "Practical examples", 0.0),
+        DemoInfo::new(2, "partial_move", "Partial Moves", "Moving one field out of a struct", 0.0),
+        DemoInfo::new(2, "clone_cost", "Clone Cost", "Clones copy heap data, moves don't", 0.1),
+        DemoInfo::new(3, "immutable", "Immutable Borrowing", "Reading data without taking ownership", 0.0),
+        DemoInfo::new(3, "mutable", "Mutable Borrowing", "Modifying data through references", 0.0),
+        DemoInfo::new(3, "rules", "Borrowing Rules", "The borrow checker in action", 0.0),
+        DemoInfo::new(3, "lifetimes", "Lifetime Annotations", "Explicit lifetime management", 0.0),
+        DemoInfo::new(3, "elision", "Lifetime Elision", "When you don't need explicit lifetimes", 0.0),
+        DemoInfo::new(3, "patterns", "Common Reference Patterns", "Practical borrowing scenarios", 0.0),
+        DemoInfo::new(3, "dangling", "Dangling References", "What the borrow checker prevents", 0.0),
+        DemoInfo::new(3, "cell", "Cell", "Interior mutability for Copy types without borrows", 0.0),
+        DemoInfo::new(3, "lifetime_bounds", "Lifetime Bounds", "T: 'a on generic types holding references", 0.0),
+        DemoInfo::new(3, "lifetime_methods", "Lifetimes in Methods", "Elision when &self is an input", 0.0),
+        DemoInfo::new(3, "static", "'static", "The lifetime vs the bound", 0.0),
+        DemoInfo::new(4, "basic", "Basic Traits", "Defining shared behavior", 0.0),
+        DemoInfo::new(4, "generics", "Generic Functions", "Functions that work with multiple types", 0.0),
+        DemoInfo::new(4, "objects", "Trait Objects and Dynamic Dispatch", "Runtime polymorphism with dyn Trait", 0.0),
+        DemoInfo::new(4, "structs", "Generic Structs and Implementations", "Type parameters on structs and impl blocks", 0.0),
+        DemoInfo::new(4, "associated", "Associated Types and Advanced Traits", "Associated types, defaults and supertraits", 0.0),
+        DemoInfo::new(4, "operators", "Operator Overloading with Traits", "Implementing std::ops for custom types", 0.0),
+        DemoInfo::new(4, "standard", "Common Standard Library Traits", "Debug, Clone, PartialEq, Default and friends", 0.0),
+        DemoInfo::new(4, "generic_methods", "Generic Methods on Traits", "A method with its own type parameter", 0.0),
+        DemoInfo::new(4, "object_safety", "Object Safety", "Which traits can become trait objects", 0.0),
+        DemoInfo::new(4, "custom_ord", "Custom Ord", "Hand-written comparisons for sorting", 0.0),
+        DemoInfo::new(4, "more_operators", "More Operators", "Sub, Mul, Neg, compound assignment and Index", 0.0),
+        DemoInfo::new(4, "generic_ordering", "Generic Ordering", "Comparison bounds in generic functions", 0.0),
+        DemoInfo::new(4, "phantom", "PhantomData", "Zero-sized type tags for units", 0.0),
+        DemoInfo::new(4, "downcast", "Downcasting", "Recovering concrete types from trait objects", 0.0),
+        DemoInfo::new(4, "memoize", "Memoize", "A generic struct that caches a closure's results", 0.0),
+        DemoInfo::new(5, "basic", "Basic Enums", "Defining types with multiple variants", 0.0),
+        DemoInfo::new(5, "data", "Enums with Data", "Variants can hold different types of data", 0.0),
+        DemoInfo::new(5, "option", "Option<T>", "Handling the absence of values safely", 0.0),
+        DemoInfo::new(5, "result", "Result<T, E>", "Comprehensive error handling", 0.0),
+        DemoInfo::new(5, "patterns", "Advanced Pattern Matching", "Complex patterns and guards", 0.0),
+        DemoInfo::new(5, "recursive", "Recursive Enums", "Building complex data structures", 0.0),
+        DemoInfo::new(5, "propagation", "Error Propagation with ? operator", "Bubbling errors up with ?", 0.0),
+        DemoInfo::new(5, "res_combinators", "Result Combinators", "Chaining fallible steps without match", 0.0),
+        DemoInfo::new(5, "main_q", "? in main", "Returning Result<(), Box<dyn Error>> from the top", 0.0),
+        DemoInfo::new(5, "non_exhaustive", "Non-exhaustive Enums", "Leaving room for future variants", 0.0),
+        DemoInfo::new(5, "state_machine", "State Machine", "Enums and match as a finite state machine", 0.0),
+        DemoInfo::new(5, "tagged_json", "Tagged JSON", "Deserializing wire commands straight into an enum", 0.0),
+        DemoInfo::new(5, "error_boxing", "Error Boxing", "Box<dyn Error> vs a typed error enum", 0.0),
+        DemoInfo::new(6, "iterators", "Iterator Patterns", "Functional programming in Rust", 0.0),
+        DemoInfo::new(6, "advanced_iterators", "Advanced Iterator Techniques", "Custom iterators, laziness and flat_map", 0.0),
+        DemoInfo::new(6, "errors", "Error Handling Patterns", "Idiomatic ways to handle and convert errors", 0.0),
+        DemoInfo::new(6, "shadowing", "Variable Shadowing and Type Transformations", "Rebinding names to refine values", 0.0),
+        DemoInfo::new(6, "memory", "Memory-Efficient Patterns", "Slices, iterator chains, Cow and capacity hints", 0.0),
+        DemoInfo::new(6, "utilities", "Common Utility Patterns", "Builder and newtype patterns", 0.0),
+        DemoInfo::new(6, "combining", "Combining Iterators", "flatten, chain, rev and cycle", 0.0),
+        DemoInfo::new(6, "cow_normalize", "Cow Normalization", "Allocate only when the input must change", 0.0),
+        DemoInfo::new(6, "sealed", "Sealed Extension Traits", "Adding methods others can't implement", 0.0),
+        DemoInfo::new(6, "splitting", "String Splitting", "split, splitn, rsplit and friends", 0.0),
+        DemoInfo::new(6, "entry_api", "Entry API", "Counting and grouping with HashMap::entry", 0.0),
+        DemoInfo::new(6, "closures", "Closures and Fn Traits", "Fn, FnMut, FnOnce and move", 0.0),
+        DemoInfo::new(6, "boxed_closures", "Boxed Closures", "Storing closures in vectors and structs", 0.0),
+        DemoInfo::new(6, "aggregations", "Aggregations", "sum, min, max and friends over structs", 0.0),
+        DemoInfo::new(6, "vec_mutation", "Vec Mutation", "retain, drain, dedup and swap_remove", 0.0),
+        DemoInfo::new(6, "vecdeque", "VecDeque", "Queues and sliding windows", 0.0),
+        DemoInfo::new(6, "btree_nav", "BTreeMap Navigation", "Ordered ranges, first and last", 0.0),
+        DemoInfo::new(6, "string_conversions", "String Conversions", "A parse, build and transform cookbook", 0.0),
+        DemoInfo::new(6, "try_fold", "try_fold", "Fallible iteration that stops at the first error", 0.0),
+        DemoInfo::new(6, "collect_targets", "Collect Targets", "One iterator, many container types", 0.0),
+        DemoInfo::new(7, "threading", "Basic Threading", "Spawning and joining threads", 0.8),
+        DemoInfo::new(7, "channels", "Message Passing", "Communication between threads using channels", 1.9),
+        DemoInfo::new(7, "shared", "Shared State", "Using Arc and Mutex for shared data", 0.1),
+        DemoInfo::new(7, "advanced", "Deadlock Prevention and Advanced Patterns", "RwLock readers/writers and scoped threads", 0.7),
+        DemoInfo::new(7, "async", "Async/Await Basics", "Futures and async functions with tokio-like patterns", 0.3),
+        DemoInfo::new(7, "safety", "Thread Safety and Send/Sync Traits", "How the compiler enforces thread safety", 0.0),
+        DemoInfo::new(7, "barrier", "Barrier", "Making threads rendezvous at a synchronization point", 0.4),
+        DemoInfo::new(7, "real_async", "Real Async", "Driving futures with the tokio runtime", 0.7),
+        DemoInfo::new(7, "deadlock", "Deadlock Avoidance", "Rust prevents data races, not deadlocks", 0.3),
+        DemoInfo::new(7, "worker_pool", "Worker Pool", "A shared job queue with crossbeam channels", 0.2),
+        DemoInfo::new(7, "recv_timeout", "Receive with Timeout", "Consumers that never hang forever", 1.4),
+        DemoInfo::new(7, "crossbeam_scope", "Crossbeam Scope", "Scoped threads before std::thread::scope", 0.0),
+        DemoInfo::new(7, "shutdown", "Graceful Shutdown", "Stopping workers with a shared flag", 0.4),
+        DemoInfo::new(7, "pipeline", "Pipeline", "Fan-out to workers, fan-in to a collector", 0.2),
+        DemoInfo::new(7, "once", "Once", "One-time initialization across threads", 0.0),
+        DemoInfo::new(7, "thread_panic", "Thread Panics", "Recovering a panic from join()", 0.1),
+        DemoInfo::new(7, "channel_bench", "Channel Benchmark", "std::sync::mpsc vs crossbeam throughput", 0.5),
+        DemoInfo::new(7, "send_sync", "Send and Sync Markers", "Opting in with unsafe impl, opting out with PhantomData", 0.0),
+        DemoInfo::new(7, "cancellation", "Cancellation Token", "A reusable shutdown signal shared by workers", 0.2),
+        DemoInfo::new(8, "serde", "Serde + serde_json", "Serialization and Deserialization", 0.0),
+        DemoInfo::new(8, "rand", "Rand", "Random Number Generation", 0.0),
+        DemoInfo::new(8, "clap", "Clap", "Command Line Argument Parsing", 0.0),
+        DemoInfo::new(8, "tokio", "Tokio", "Asynchronous Runtime (simplified for demo)", 0.8),
+        DemoInfo::new(8, "reqwest", "Reqwest", "HTTP Client", 0.1),
+        DemoInfo::new(8, "regex", "Regex", "Regular Expressions", 0.7),
+        DemoInfo::new(8, "chrono", "Chrono", "Date and Time Handling", 0.0),
+        DemoInfo::new(8, "anyhow", "Anyhow", "Error Handling with Context", 0.0),
+        DemoInfo::new(8, "thiserror", "Thiserror", "Custom Error Types", 0.0),
+        DemoInfo::new(8, "crossbeam", "Crossbeam", "Advanced Concurrency", 2.1),
+        DemoInfo::new(8, "rayon", "Rayon", "Data Parallelism", 2.7),
+        DemoInfo::new(8, "tracing", "Tracing", "Structured Logging", 0.0),
+        DemoInfo::new(8, "log", "Log + env_logger", "Traditional Logging", 0.0),
+        DemoInfo::new(8, "itertools", "Itertools", "Extended Iterator Methods", 0.0),
+        DemoInfo::new(8, "once_cell", "Once_cell", "Lazy Static Initialization", 0.0),
+        DemoInfo::new(8, "uuid", "UUID", "Unique Identifier Generation", 0.0),
+        DemoInfo::new(8, "tempfile", "Tempfile", "Temporary File Management", 0.0),
+        DemoInfo::new(8, "bitflags", "Bitflags", "Type-safe Bit Flag Operations", 0.0),
+        DemoInfo::new(8, "parking_lot", "Parking_lot", "High-performance Synchronization", 0.7),
+        DemoInfo::new(8, "collections", "Advanced Collections", "Concurrent map patterns (simulating dashmap)", 0.0),
+        DemoInfo::new(8, "csv", "CSV Parsing", "Tabular data with a hand-rolled splitter + serde typing", 0.0),
+        DemoInfo::new(8, "toml", "TOML", "Typed configuration files with serde", 0.0),
+        DemoInfo::new(8, "indicatif", "Indicatif", "Progress Bars and Spinners", 0.7),
+        DemoInfo::new(8, "process", "Process", "Running External Commands", 0.0),
+        DemoInfo::new(8, "filesystem", "Filesystem", "Recursive Directory Walking", 0.0),
+        DemoInfo::new(8, "env", "Env", "Configuration from Environment Variables", 0.0),
+        DemoInfo::new(8, "base64", "Base64", "Encoding Binary Data as Text", 0.0),
+        DemoInfo::new(8, "hashing", "Hashing", "SHA-256 Digests for Integrity and Caching", 0.0),
+    ]
+}
+
+/// Serialize the demo catalog to JSON, ordered by section then key
+pub fn demos_manifest_json() -> String {
+    let mut demos = all_demos();
+    demos.sort_by(|a, b| (a.section, a.key).cmp(&(b.section, b.key)));
+    serde_json::to_string_pretty(&demos).unwrap()
+}
+
+/// Print the demos `run_all_sections` and the enhanced "all" mode would run,
+/// in order, without executing any of them
+pub fn print_dry_run() {
+    println!("{}", DRY_RUN_HEADING);
+    println!("Timings are measured execution times (excluding network latency), not presentation time.");
+    println!();
+    
+    let demos = all_demos();
+    let mut current_section = 0;
+    for demo in &demos {
+        if demo.section != current_section {
+            current_section = demo.section;
+            let section = Section::try_from(current_section).unwrap();
+            println!("Section {}: {}", section.number(), section);
+        }
+        let estimate = if demo.est_secs < 0.1 { "<0.1s".to_string() } else { format!("~{:.1}s", demo.est_secs) };
+        println!("  {:<20} {:<45} {}", demo.key, demo.title, estimate);
+    }
+    
+    let total_secs: f32 = demos.iter().map(|demo| demo.est_secs).sum();
+    println!();
+    println!("{} demos, about {:.1}s of execution time", demos.len(), total_secs);
+}
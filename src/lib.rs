@@ -4,6 +4,24 @@
 //! This library provides a comprehensive set of Rust examples organized
 //! into sections that can be demonstrated during live lectures.
 
+/// Banner decoration; plain ASCII when built with the `ascii` feature
+#[cfg(not(feature = "ascii"))]
+macro_rules! crab { () => { "🦀" } }
+#[cfg(feature = "ascii")]
+macro_rules! crab { () => { "##" } }
+
+/// "Done" marker for section footers; plain ASCII with the `ascii` feature
+#[cfg(not(feature = "ascii"))]
+macro_rules! check { () => { "✅" } }
+#[cfg(feature = "ascii")]
+macro_rules! check { () => { "[OK]" } }
+
+/// Emoji marker with a plain-ASCII stand-in used by the `ascii` feature
+#[cfg(not(feature = "ascii"))]
+macro_rules! icon { ($emoji:literal, $ascii:literal) => { $emoji } }
+#[cfg(feature = "ascii")]
+macro_rules! icon { ($emoji:literal, $ascii:literal) => { $ascii } }
+
 pub mod demo_runner;
 pub mod section1_basics;
 pub mod section2_ownership;
@@ -17,5 +35,7 @@ pub mod section8_crates;
 // Re-export the main demo runner for easy access
 pub use demo_runner::run_interactive_demo;
 pub use demo_runner::run_all_sections;
+pub use demo_runner::run_kiosk_loop;
 pub use demo_runner::individual_demos;
-pub use demo_runner::lecture_utils;
\ No newline at end of file
+pub use demo_runner::lecture_utils;
+pub use demo_runner::{all_demos, demos_manifest_json, print_dry_run, set_clear_screen, DemoInfo, Section};
\ No newline at end of file
@@ -4,9 +4,35 @@
 //! Run this file to start the interactive lecture demonstration system.
 //! You can run individual sections or demos during your live lecture.
 
-use lecture::run_interactive_demo;
+use lecture::{demos_manifest_json, print_dry_run, run_interactive_demo, run_kiosk_loop, set_clear_screen};
 
 fn main() {
+    // Print the demo catalog as JSON for external tools and exit
+    if std::env::args().any(|arg| arg == "--manifest") {
+        println!("{}", demos_manifest_json());
+        return;
+    }
+    
+    // List the run-all order with time estimates, without running anything
+    if std::env::args().any(|arg| arg == "--dry-run") {
+        print_dry_run();
+        return;
+    }
+    
+    // Keep scrollback (for recordings, notes, or piping to a file)
+    let no_clear_flag = std::env::args().any(|arg| arg == "--no-clear");
+    let no_clear_env = std::env::var_os("LECTURE_NO_CLEAR").is_some();
+    if no_clear_flag || no_clear_env {
+        set_clear_screen(false);
+    }
+    
+    // Loop every section unattended, auto-advancing after a countdown (--kiosk=SECS)
+    if let Some(arg) = std::env::args().find(|arg| arg == "--kiosk" || arg.starts_with("--kiosk=")) {
+        let secs = arg.strip_prefix("--kiosk=").and_then(|s| s.parse().ok()).unwrap_or(10);
+        run_kiosk_loop(secs);
+        return;
+    }
+    
     // Start the interactive demo system
     run_interactive_demo();
 }
@@ -28,7 +54,7 @@ fn main_individual_demos() {
     print_section_separator("Custom Demo Sequence");
     
     // Run specific demos in a custom order
-    println!("🎯 Running a custom sequence of demos...\n");
+    println!("{} Running a custom sequence of demos...\n", SEQUENCE_MARKER);
     
     lecture_pause("About to demonstrate basic Rust syntax");
     run_section1_demo("hello");
@@ -47,7 +73,7 @@ fn main_individual_demos() {
         "Rust's ownership system prevents memory leaks and data races at compile time!"
     );
     
-    println!("\n🎉 Custom demo sequence completed!");
+    println!("\n{} Custom demo sequence completed!", CELEBRATE_MARKER);
 }
 
 /// Example of using the lecture utilities
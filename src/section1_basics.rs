@@ -17,6 +17,7 @@ pub fn demo_hello_world() {
 }
 
 /// Demo 1b: Variables and Mutability - Rust's default immutability
+#[allow(clippy::assign_op_pattern)]
 pub fn demo_variables_mutability() {
     println!("=== Demo 1b: Variables and Mutability ===");
     
@@ -155,9 +156,291 @@ pub fn demo_block_expressions() {
     println!();
 }
 
+/// Demo 1i: String Formatting - Width, precision, alignment and radixes
+pub fn demo_formatting() {
+    println!("=== Demo 1i: String Formatting ===");
+    
+    let items = [("apple", 3, 0.5), ("banana", 12, 0.25), ("cherry", 150, 4.0)];
+    
+    // Width and alignment make a table line up
+    println!("{:<10}|{:>8}|{:>10}", "item", "qty", "price");
+    println!("{}", "-".repeat(30));
+    for (name, qty, price) in items {
+        println!("{:<10}|{:>8}|{:>10.2}", name, qty, price);
+    }
+    
+    // Zero padding, precision and explicit sign
+    let pi = std::f64::consts::PI;
+    println!("Zero-padded float: {:08.2}", pi);
+    println!("Always show sign: {:+} and {:+}", 42, -7);
+    
+    // Other radixes (# adds the 0x / 0b prefix)
+    let value = 255;
+    println!("Hex: {:x}, binary: {:b}, with prefix: {:#x}", value, value, value);
+    
+    // Identifiers in scope can be captured directly
+    let name = "Ferris";
+    let age = 8;
+    println!("Captured: {name} is {age} years old");
+    println!("Captured with width: [{name:^10}]");
+    println!();
+}
+
+/// Demo 1j: Chars and Unicode - A char is a Unicode scalar, not a byte
+pub fn demo_chars() {
+    println!("=== Demo 1j: Chars and Unicode ===");
+    
+    let text = "café🦀";
+    
+    // len() counts bytes, chars() counts Unicode scalar values
+    println!("Text: {}", text);
+    println!("Bytes (len): {}", text.len());
+    println!("Chars (chars().count()): {}", text.chars().count());
+    
+    for c in text.chars() {
+        println!("  '{}' uses {} byte(s), U+{:04X}", c, c.len_utf8(), c as u32);
+    }
+    
+    let bytes: Vec<u8> = text.bytes().collect();
+    println!("Raw bytes: {:?}", bytes);
+    
+    // char has its own methods
+    for c in ['a', 'é', '7', '🦀'] {
+        let upper: String = c.to_uppercase().collect();
+        println!("  '{}': alphabetic={}, numeric={}, uppercase='{}'", c, c.is_alphabetic(), c.is_numeric(), upper);
+    }
+    println!();
+}
+
+/// Demo 1k: Recursion - Base cases and the call stack
+pub fn demo_recursion() {
+    println!("=== Demo 1k: Recursion ===");
+    
+    // Every recursive function needs a base case that stops the calls
+    fn factorial(n: u64, depth: usize) -> u64 {
+        let indent = "  ".repeat(depth);
+        println!("{}factorial({})", indent, n);
+        if n <= 1 {
+            1
+        } else {
+            n * factorial(n - 1, depth + 1)
+        }
+    }
+    
+    let result = factorial(4, 0);
+    println!("factorial(4) = {}", result);
+    
+    // Naive Fibonacci makes two calls per step, so the calls grow fast
+    fn fib(n: u32, calls: &mut u32) -> u64 {
+        *calls += 1;
+        if n < 2 {
+            n as u64
+        } else {
+            fib(n - 1, calls) + fib(n - 2, calls)
+        }
+    }
+    
+    for n in [5, 10, 20] {
+        let mut calls = 0;
+        let value = fib(n, &mut calls);
+        println!("fib({}) = {} ({} calls)", n, value, calls);
+    }
+    
+    // Each call uses a stack frame; very deep recursion overflows the stack,
+    // so a loop is the safer choice when depth depends on input
+    fn fib_iterative(n: u32) -> u64 {
+        let (mut a, mut b) = (0u64, 1u64);
+        for _ in 0..n {
+            let next = a + b;
+            a = b;
+            b = next;
+        }
+        a
+    }
+    
+    println!("fib_iterative(20) = {}", fib_iterative(20));
+    println!("fib_iterative(90) = {} (no deep call stack needed)", fib_iterative(90));
+    println!();
+}
+
+/// Demo 1l: Timing - Measuring and pausing with std::time
+pub fn demo_timing() {
+    println!("=== Demo 1l: Timing ===");
+    
+    use std::thread;
+    use std::time::{Duration, Instant};
+    
+    // Instant is a monotonic clock: perfect for measuring elapsed time
+    let start = Instant::now();
+    let mut sum: u64 = 0;
+    for i in 0..100_000 {
+        sum += i;
+    }
+    let elapsed = start.elapsed();
+    println!("Summed 0..100_000 = {} in {:?}", sum, elapsed);
+    
+    // A Duration can be viewed in whichever unit reads best
+    println!("  as_millis: {} ms", elapsed.as_millis());
+    println!("  as_micros: {} µs", elapsed.as_micros());
+    println!("  as_secs_f64: {:.6} s", elapsed.as_secs_f64());
+    
+    // Building durations and doing arithmetic on them
+    let half_second = Duration::from_millis(500);
+    let timeout = Duration::from_secs(2) + half_second;
+    println!("\n2s + 500ms = {:?}", timeout);
+    println!("Timeout as millis: {}", timeout.as_millis());
+    
+    // sleep blocks the current thread for *at least* the given time
+    let nap = Duration::from_millis(50);
+    let before = Instant::now();
+    thread::sleep(nap);
+    let slept = before.elapsed();
+    println!("\nAsked to sleep {:?}, actually slept {:?}", nap, slept);
+    println!("Slept at least as long as asked: {}", slept >= nap);
+    println!();
+}
+
+/// Demo 1m: Operators - Arithmetic, comparison, boolean and bitwise
+pub fn demo_operators() {
+    println!("=== Demo 1m: Operators ===");
+    
+    // Integer division truncates toward zero; float division doesn't
+    println!("7 / 2     = {}   (integers: the .5 is dropped)", 7 / 2);
+    println!("7.0 / 2.0 = {} (floats)", 7.0 / 2.0);
+    println!("-7 / 2    = {}  (truncates toward zero, not down)", -7 / 2);
+    
+    // % is a remainder: its sign follows the left operand
+    println!("\n7 % 3     = {}", 7 % 3);
+    println!("-7 % 3    = {}  (surprise: not 2)", -7 % 3);
+    println!("(-7i32).rem_euclid(3) = {}  (always non-negative)", (-7i32).rem_euclid(3));
+    
+    // Comparisons produce bools; both sides must be the same type
+    let (a, b) = (5, 10);
+    println!("\n{} < {} = {}, {} == {} = {}, {} != {} = {}", a, b, a < b, a, b, a == b, a, b, a != b);
+    // println!("{}", 5 < 10.0); // This would cause a compile error! No implicit int/float mix
+    
+    // && and || short-circuit: the right side only runs when needed
+    fn check(label: &str, result: bool) -> bool {
+        println!("    evaluated {}", label);
+        result
+    }
+    let logged_in = false;
+    let is_admin = true;
+    println!("\nlogged_in && check(..) with logged_in = false:");
+    let and = logged_in && check("right side of &&", true);
+    println!("  = {} (right side skipped)", and);
+    println!("is_admin || check(..) with is_admin = true:");
+    let or = is_admin || check("right side of ||", false);
+    println!("  = {} (right side skipped)", or);
+    println!("is_admin && check(..):");
+    let both = is_admin && check("right side of &&", true);
+    println!("  = {}", both);
+    
+    // Bitwise operators work on the binary representation
+    let x: u8 = 0b1100;
+    let y: u8 = 0b1010;
+    println!("\nx = {:04b}, y = {:04b}", x, y);
+    println!("x & y  = {:04b} (and)", x & y);
+    println!("x | y  = {:04b} (or)", x | y);
+    println!("x ^ y  = {:04b} (xor)", x ^ y);
+    println!("x << 2 = {:06b} ({})", x << 2, x << 2);
+    println!("x >> 2 = {:04b} ({})", x >> 2, x >> 2);
+    println!("!x     = {:08b} (not, all 8 bits flip)", !x);
+    println!();
+}
+
+/// Demo 1n: Slicing and Indexing - Arrays, ranges and safe access
+pub fn demo_slicing() {
+    println!("=== Demo 1n: Slicing and Indexing ===");
+    
+    let temps = [18, 21, 25, 23, 19, 16, 20];
+    println!("temps = {:?} (length {})", temps, temps.len());
+    
+    // Indexing starts at 0
+    println!("temps[0] = {}, temps[3] = {}", temps[0], temps[3]);
+    
+    // Ranges borrow a slice: start is included, end is excluded
+    let midweek: &[i32] = &temps[1..3];
+    println!("&temps[1..3]  = {:?}", midweek);
+    println!("&temps[..2]   = {:?}", &temps[..2]);
+    println!("&temps[5..]   = {:?}", &temps[5..]);
+    println!("&temps[2..=4] = {:?} (..= includes the end)", &temps[2..=4]);
+    
+    // first/last return Option because a slice can be empty
+    println!("\nfirst() = {:?}, last() = {:?}", temps.first(), temps.last());
+    let empty: &[i32] = &[];
+    println!("On an empty slice: first() = {:?}", empty.first());
+    
+    // temps[99] would panic at runtime: "index out of bounds: the len is 7 but the index is 99"
+    // .get() returns an Option instead, so a bad index is something you can handle
+    for i in [2, 99] {
+        match temps.get(i) {
+            Some(t) => println!("temps.get({}) = Some({})", i, t),
+            None => println!("temps.get({}) = None (no panic)", i),
+        }
+    }
+    // get works with ranges too
+    println!("temps.get(5..10) = {:?}", temps.get(5..10));
+    
+    // String slices index by *byte*, so stay on char boundaries
+    let greeting = "hello world";
+    println!("\n&greeting[0..5] = {:?}", &greeting[0..5]);
+    println!();
+}
+
+/// Demo 1o: Shadowing - Rebinding a name vs mutating a variable
+pub fn demo_shadowing_basics() {
+    println!("=== Demo 1o: Shadowing ===");
+    
+    fn type_of<T>(_: &T) -> &'static str {
+        std::any::type_name::<T>()
+    }
+    
+    // Each `let` creates a brand-new variable that hides the old one
+    let x = 5;
+    println!("let x = 5;              x = {:<14} ({})", x, type_of(&x));
+    let x = x + 1;
+    println!("let x = x + 1;          x = {:<14} ({})", x, type_of(&x));
+    let x = "now a string";
+    println!("let x = \"now a string\"; x = {:<14} ({})", x, type_of(&x));
+    
+    // Shadowing in an inner block ends with the block
+    let y = 10;
+    {
+        let y = y * 2;
+        println!("\nInner block: y = {}", y);
+    }
+    println!("Outer block: y = {} (the shadow is gone)", y);
+    
+    // mut changes the value but the type is fixed
+    let mut count = 1;
+    count += 1;
+    println!("\nlet mut count = 1; count += 1; -> {} ({})", count, type_of(&count));
+    // count = "two"; // This would cause a compile error! mut can't change the type
+    
+    // A common use: parsing input into a new type under the same name
+    let input = "42";
+    let input: i32 = input.parse().unwrap();
+    println!("Parsed input shadows the &str: {} ({})", input, type_of(&input));
+    println!();
+}
+
+/// Header printed by `run_all_demos`
+pub const BANNER: &str = concat!(crab!(), " RUST LECTURE - SECTION 1: BASIC SYNTAX AND CONSTRUCTS ", crab!());
+
+/// Concepts listed in the recap printed after `run_all_demos`
+pub const RECAP: &[&str] = &[
+    "Variables are immutable unless declared mut",
+    "Functions return their last expression",
+    "if, match and blocks are expressions",
+    "for walks collections; while loops on a condition",
+    "Format specs control width, precision and radix",
+    "A char is a Unicode scalar value, not a byte",
+];
+
 /// Run all demos in sequence
 pub fn run_all_demos() {
-    println!("🦀 RUST LECTURE - SECTION 1: BASIC SYNTAX AND CONSTRUCTS 🦀");
+    println!("{}", BANNER);
     println!("============================================================");
     println!();
     
@@ -169,8 +452,16 @@ pub fn run_all_demos() {
     demo_for_loops();
     demo_while_loops();
     demo_block_expressions();
+    demo_formatting();
+    demo_chars();
+    demo_recursion();
+    demo_timing();
+    demo_operators();
+    demo_slicing();
+    demo_shadowing_basics();
     
-    println!("✅ Section 1 complete!");
+    println!(concat!(check!(), " Section 1 complete!"));
+    crate::lecture_utils::print_recap("Section 1: Basic Syntax", RECAP);
 }
 
 /// Get list of available demos for enhanced navigation
@@ -184,5 +475,12 @@ pub fn get_demo_list() -> Vec<&'static str> {
         "for",
         "while",
         "blocks",
+        "format",
+        "chars",
+        "recursion",
+        "timing",
+        "operators",
+        "slicing",
+        "shadowing",
     ]
 }
\ No newline at end of file
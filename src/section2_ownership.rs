@@ -160,9 +160,90 @@ pub fn demo_ownership_patterns() {
     println!();
 }
 
+/// Demo 2g: Partial Moves - Moving one field out of a struct
+pub fn demo_partial_move() {
+    println!("=== Demo 2g: Partial Moves ===");
+    
+    #[derive(Debug)]
+    struct User {
+        name: String,
+        email: String,
+    }
+    
+    let user = User {
+        name: String::from("Alice"),
+        email: String::from("alice@example.com"),
+    };
+    
+    // Move only the name field out of the struct
+    let name = user.name;
+    println!("Moved out name: {}", name);
+    
+    // println!("{}", user.name); // This would cause a compile error! (name was moved)
+    
+    // The other field is still owned by `user` and can be used
+    println!("Email is still usable: {}", user.email);
+    
+    // println!("{:?}", user); // This would cause a compile error! (user is partially moved)
+    // let copy = user;        // Same problem: the whole struct can't move anymore
+    
+    // Borrowing a field instead of moving it keeps the struct intact
+    let other = User {
+        name: String::from("Bob"),
+        email: String::from("bob@example.com"),
+    };
+    let name_ref = &other.name;
+    println!("Borrowed name: {}", name_ref);
+    println!("Whole struct still usable: {:?}", other);
+    println!();
+}
+
+/// Demo 2h: Clone Cost - Clones copy heap data, moves don't
+pub fn demo_clone_cost() {
+    println!("=== Demo 2h: Clone Cost ===");
+    
+    use std::time::Instant;
+    
+    let buffer: Vec<u8> = vec![42; 50_000_000];
+    
+    // clone() allocates and copies every byte
+    let start = Instant::now();
+    let cloned = buffer.clone();
+    let clone_time = start.elapsed();
+    
+    // A move only copies the (pointer, length, capacity) triple
+    let start = Instant::now();
+    let moved = buffer;
+    let move_time = start.elapsed();
+    
+    println!("Buffer size: {} MB", moved.len() / 1_000_000);
+    println!("clone() took: {:?}", clone_time);
+    println!("move took:    {:?}", move_time);
+    println!("Both hold the same data: {}", cloned == moved);
+    
+    // Copy types are just bits on the stack: "move" and copy are the same thing
+    let x: i32 = 5;
+    let y = x; // copied, x is still usable
+    println!("Copy type: x = {}, y = {} (no clone cost to worry about)", x, y);
+    println!();
+}
+
+/// Header printed by `run_all_demos`
+pub const BANNER: &str = concat!(crab!(), " RUST LECTURE - SECTION 2: OWNERSHIP AND MOVE SEMANTICS ", crab!());
+
+/// Concepts listed in the recap printed after `run_all_demos`
+pub const RECAP: &[&str] = &[
+    "Every value has exactly one owner",
+    "Values are dropped when their owner goes out of scope",
+    "Assignment moves heap data; Copy types are duplicated",
+    "Passing a value to a function moves it unless borrowed",
+    "Partial moves take one field out of a struct",
+    "clone() is an explicit, sometimes costly deep copy",
+];
+
 /// Run all demos in sequence
 pub fn run_all_demos() {
-    println!("🦀 RUST LECTURE - SECTION 2: OWNERSHIP AND MOVE SEMANTICS 🦀");
+    println!("{}", BANNER);
     println!("==============================================================");
     println!();
     
@@ -172,9 +253,11 @@ pub fn run_all_demos() {
     demo_function_ownership();
     demo_collection_ownership();
     demo_ownership_patterns();
+    demo_partial_move();
+    demo_clone_cost();
     
-    println!("✅ Section 2 complete!");
-    println!("💡 Key takeaway: Rust's ownership system prevents memory leaks and data races at compile time!");
+    println!(concat!(check!(), " Section 2 complete!"));
+    crate::lecture_utils::print_recap("Section 2: Ownership", RECAP);
 }
 
 /// Get list of available demos for enhanced navigation
@@ -186,5 +269,7 @@ pub fn get_demo_list() -> Vec<&'static str> {
         "functions",
         "collections",
         "patterns",
+        "partial_move",
+        "clone_cost",
     ]
 }
\ No newline at end of file
@@ -13,6 +13,7 @@
 pub fn demo_immutable_borrowing() {
     println!("=== Demo 3a: Immutable Borrowing ===");
     
+    #[allow(clippy::ptr_arg)]
     fn calculate_length(s: &String) -> usize {
         s.len()  // We can read the string but not modify it
     }
@@ -36,6 +37,7 @@ pub fn demo_immutable_borrowing() {
 pub fn demo_mutable_borrowing() {
     println!("=== Demo 3b: Mutable Borrowing ===");
     
+    #[allow(clippy::single_char_add_str)]
     fn append_exclamation(s: &mut String) {
         s.push_str("!");
     }
@@ -135,6 +137,7 @@ pub fn demo_lifetime_elision() {
     // These functions don't need explicit lifetime annotations
     // due to lifetime elision rules
     
+    #[allow(clippy::redundant_slicing)]
     fn first_word(s: &str) -> &str {
         let bytes = s.as_bytes();
         for (i, &item) in bytes.iter().enumerate() {
@@ -211,6 +214,7 @@ pub fn demo_dangling_prevention() {
     // }
     
     // Correct way: return owned data
+    #[allow(clippy::let_and_return)]
     fn no_dangle() -> String {
         let s = String::from("hello");
         s  // Return ownership, not a reference
@@ -231,9 +235,213 @@ pub fn demo_dangling_prevention() {
     println!();
 }
 
+/// Demo 3h: Cell - Interior mutability for Copy types without borrows
+pub fn demo_cell() {
+    println!("=== Demo 3h: Cell ===");
+    
+    use std::cell::{Cell, RefCell};
+    
+    struct PageView {
+        url: String,
+        hits: Cell<u32>,
+    }
+    
+    impl PageView {
+        // Only &self is needed, yet the counter can change
+        fn record_hit(&self) {
+            self.hits.set(self.hits.get() + 1);
+        }
+    }
+    
+    let page = PageView {
+        url: String::from("/index"),
+        hits: Cell::new(0),
+    };
+    
+    page.record_hit();
+    page.record_hit();
+    page.record_hit();
+    println!("{} has {} hits", page.url, page.hits.get());
+    
+    // replace() swaps in a new value and returns the old one
+    let previous = page.hits.replace(0);
+    println!("Reset counter (was {}), now {}", previous, page.hits.get());
+    
+    // Cell never hands out references: values are copied in and out,
+    // so there is nothing to borrow-check at runtime
+    let cell = Cell::new(10);
+    let snapshot = cell.get();
+    cell.set(20);
+    println!("Cell snapshot: {}, current: {}", snapshot, cell.get());
+    
+    // RefCell hands out borrows instead, checked at runtime
+    let log = RefCell::new(Vec::new());
+    log.borrow_mut().push("first entry");
+    println!("RefCell contents: {:?}", log.borrow());
+    
+    let _reader = log.borrow();
+    println!("try_borrow_mut() fails while a borrow is active: {}", log.try_borrow_mut().is_err());
+    println!("(Cell can't fail like this, but only works well for Copy types)");
+    println!();
+}
+
+/// Demo 3i: Lifetime Bounds - T: 'a on generic types holding references
+pub fn demo_lifetime_bounds() {
+    println!("=== Demo 3i: Lifetime Bounds ===");
+    
+    // T: 'a means "any references inside T must outlive 'a", so a
+    // &'a T can never point at something that dies first.
+    // (Modern Rust infers this bound on structs, but it's good to read.)
+    #[derive(Debug)]
+    struct Holder<'a, T: 'a> {
+        item: &'a T,
+    }
+    
+    impl<'a, T: std::fmt::Debug> Holder<'a, T> {
+        fn show(&self) {
+            println!("Holder contains: {:?}", self.item);
+        }
+    }
+    
+    // Both inputs and the output share one lifetime
+    fn longest_ref<'a, T>(a: &'a T, b: &'a T) -> &'a T
+    where
+        T: PartialOrd,
+    {
+        if a >= b { a } else { b }
+    }
+    
+    let number = 42;
+    let holder = Holder { item: &number };
+    holder.show();
+    
+    let words = vec!["borrow", "checker"];
+    let word_holder = Holder { item: &words };
+    word_holder.show();
+    
+    let x = 3.5;
+    let y = 7.25;
+    println!("Larger of {} and {}: {}", x, y, longest_ref(&x, &y));
+    
+    let a = String::from("apple");
+    let result;
+    {
+        let b = String::from("banana");
+        // The result can only live as long as the shorter-lived input
+        println!("Larger string: {}", longest_ref(&a, &b));
+        result = longest_ref(&a, &a);
+    }
+    // Using a result that borrowed `b` here would be a compile error
+    println!("Result borrowed only from a: {}", result);
+    println!();
+}
+
+/// Demo 3j: Lifetimes in Methods - Elision when &self is an input
+pub fn demo_lifetime_methods() {
+    println!("=== Demo 3j: Lifetimes in Methods ===");
+    
+    // Same shape as ImportantExcerpt from Demo 3d
+    struct ImportantExcerpt<'a> {
+        part: &'a str,
+    }
+    
+    impl<'a> ImportantExcerpt<'a> {
+        // No reference in the output, so there is nothing to annotate
+        fn level(&self) -> usize {
+            self.part.split_whitespace().count()
+        }
+        
+        // Two input references, yet no annotation needed: when one input is &self,
+        // the output borrows from self (rule 3). Fully written out, this is
+        // fn announce_and_return<'b>(&'b self, announcement: &str) -> &'b str
+        fn announce_and_return(&self, announcement: &str) -> &str {
+            println!("  Attention please: {}", announcement);
+            self.part
+        }
+        
+        // Returning the *field's* lifetime 'a lets the result outlive the borrow of self
+        fn part(&self) -> &'a str {
+            self.part
+        }
+        
+        // To return `announcement` instead, we'd have to say so explicitly:
+        // fn announce(&self, announcement: &str) -> &str { announcement } // This would cause a compile error!
+    }
+    
+    let novel = String::from("Call me Ishmael. Some years ago, never mind how long precisely...");
+    let first_sentence = novel.split('.').next().expect("Could not find a '.'");
+    let excerpt = ImportantExcerpt { part: first_sentence };
+    
+    println!("Excerpt has {} words", excerpt.level());
+    let returned = excerpt.announce_and_return("the excerpt is ready");
+    println!("  Returned (borrowed from the excerpt): {:?}", returned);
+    
+    // part() returns &'a str, so it stays valid after the excerpt is dropped
+    let kept = {
+        let temporary = ImportantExcerpt { part: first_sentence };
+        temporary.part()
+    };
+    println!("Kept after the struct was dropped: {:?}", kept);
+    println!();
+}
+
+/// Demo 3k: 'static - The lifetime vs the bound
+pub fn demo_static_lifetime() {
+    println!("=== Demo 3k: 'static ===");
+    
+    use std::thread;
+    
+    // String literals live in the binary, so they are &'static str
+    let motto: &'static str = "Fearless concurrency";
+    println!("Literal: {:?} lives for the whole program", motto);
+    
+    // Box::leak gives up ownership and hands back a reference that never expires
+    let config_text = String::from("mode=lecture");
+    let leaked: &'static mut String = Box::leak(Box::new(config_text));
+    leaked.push_str(";speed=slow");
+    let leaked: &'static str = leaked; // Downgrade to a shared reference
+    println!("Leaked: {:?} (never freed: intentional, and rare)", leaked);
+    
+    // T: 'static as a *bound* means "T holds no borrows shorter than 'static",
+    // not "T lives forever". Owned values like String satisfy it.
+    fn spawn_like<T: Send + 'static>(value: T) -> thread::JoinHandle<T> {
+        thread::spawn(move || value)
+    }
+    
+    let owned = String::from("an owned String is 'static");
+    println!("\nspawn_like(String): {:?}", spawn_like(owned).join().unwrap());
+    println!("spawn_like(&'static str): {:?}", spawn_like(motto).join().unwrap());
+    println!("spawn_like(leaked): {:?}", spawn_like(leaked).join().unwrap());
+    
+    // A borrow of a local fails the bound: the thread could outlive `local`
+    let local = String::from("stack-owned");
+    // spawn_like(&local); // This would cause a compile error! `local` does not live long enough
+    // Moving it in instead satisfies 'static
+    println!("spawn_like(local moved in): {:?}", spawn_like(local).join().unwrap());
+    
+    // thread::scope relaxes the bound because the scope joins before returning
+    let counter = vec![1, 2, 3];
+    let total = thread::scope(|s| s.spawn(|| counter.iter().sum::<i32>()).join().unwrap());
+    println!("\nthread::scope can borrow {:?} without 'static: sum = {}", counter, total);
+    println!();
+}
+
+/// Header printed by `run_all_demos`
+pub const BANNER: &str = concat!(crab!(), " RUST LECTURE - SECTION 3: BORROWING, REFERENCES, AND LIFETIMES ", crab!());
+
+/// Concepts listed in the recap printed after `run_all_demos`
+pub const RECAP: &[&str] = &[
+    "&T allows many readers, &mut T exactly one writer",
+    "References can never outlive the data they point to",
+    "Lifetime annotations relate input and output borrows",
+    "Elision rules make most annotations unnecessary",
+    "Cell gives interior mutability for Copy types",
+    "T: 'a bounds limit generic types holding references",
+];
+
 /// Run all demos in sequence
 pub fn run_all_demos() {
-    println!("🦀 RUST LECTURE - SECTION 3: BORROWING, REFERENCES, AND LIFETIMES 🦀");
+    println!("{}", BANNER);
     println!("======================================================================");
     println!();
     
@@ -244,9 +452,13 @@ pub fn run_all_demos() {
     demo_lifetime_elision();
     demo_reference_patterns();
     demo_dangling_prevention();
+    demo_cell();
+    demo_lifetime_bounds();
+    demo_lifetime_methods();
+    demo_static_lifetime();
     
-    println!("✅ Section 3 complete!");
-    println!("💡 Key takeaway: Borrowing allows safe access to data without ownership transfer!");
+    println!(concat!(check!(), " Section 3 complete!"));
+    crate::lecture_utils::print_recap("Section 3: Borrowing", RECAP);
 }
 
 /// Get list of available demos for enhanced navigation
@@ -259,5 +471,9 @@ pub fn get_demo_list() -> Vec<&'static str> {
         "elision",
         "patterns",
         "dangling",
+        "cell",
+        "lifetime_bounds",
+        "lifetime_methods",
+        "static",
     ]
 }
\ No newline at end of file
@@ -59,6 +59,7 @@ pub fn demo_basic_traits() {
 }
 
 /// Demo 4b: Generic Functions - Functions that work with multiple types
+#[allow(clippy::approx_constant)]
 pub fn demo_generic_functions() {
     println!("=== Demo 4b: Generic Functions ===");
     
@@ -342,9 +343,509 @@ pub fn demo_standard_traits() {
     println!();
 }
 
+/// Demo 4h: Generic Methods on Traits - A method with its own type parameter
+pub fn demo_generic_methods() {
+    println!("=== Demo 4h: Generic Methods on Traits ===");
+    
+    use std::fmt::Display;
+    
+    // The trait itself isn't generic, but log() is: each call site
+    // picks its own D
+    trait Logger {
+        fn prefix(&self) -> String;
+        
+        fn log<D: Display>(&self, msg: D) {
+            println!("{} {}", self.prefix(), msg);
+        }
+    }
+    
+    struct ConsoleLogger {
+        name: String,
+    }
+    
+    impl Logger for ConsoleLogger {
+        fn prefix(&self) -> String {
+            format!("[{}]", self.name)
+        }
+    }
+    
+    let logger = ConsoleLogger { name: "app".to_string() };
+    logger.log("a string slice");
+    logger.log(String::from("an owned String"));
+    logger.log(42);
+    logger.log(2.5);
+    logger.log('🦀');
+    
+    // Generic methods are monomorphized: one copy of log() per D used.
+    // A vtable can only hold a fixed set of function pointers, so there's
+    // no single entry for log::<D>, and the trait isn't object safe:
+    // let boxed: Box<dyn Logger> = Box::new(logger); // This would cause a compile error!
+    
+    // Adding `where Self: Sized` keeps a generic method out of the vtable
+    trait SafeLogger {
+        fn write_line(&self, line: &str);
+        
+        fn log_value<D: Display>(&self, msg: D)
+        where
+            Self: Sized,
+        {
+            self.write_line(&msg.to_string());
+        }
+    }
+    
+    impl SafeLogger for ConsoleLogger {
+        fn write_line(&self, line: &str) {
+            println!("[{}] {}", self.name, line);
+        }
+    }
+    
+    let dyn_logger: Box<dyn SafeLogger> = Box::new(ConsoleLogger { name: "boxed".to_string() });
+    dyn_logger.write_line("write_line still works through the trait object");
+    // dyn_logger.log_value(1); // Not callable: dyn SafeLogger isn't Sized
+    ConsoleLogger { name: "concrete".to_string() }.log_value(7);
+    println!();
+}
+
+/// Demo 4i: Object Safety - Which traits can become trait objects
+pub fn demo_object_safety() {
+    println!("=== Demo 4i: Object Safety ===");
+    
+    // Object safe: every method takes &self and only mentions concrete types
+    trait Shape {
+        fn name(&self) -> String;
+        fn area(&self) -> f64;
+        // Returning a boxed trait object instead of Self keeps it object safe
+        fn boxed_clone(&self) -> Box<dyn Shape>;
+    }
+    
+    // NOT object safe: returns Self, whose size a vtable caller can't know
+    trait Duplicate {
+        fn duplicate(&self) -> Self;
+    }
+    
+    #[derive(Clone)]
+    struct Square {
+        side: f64,
+    }
+    
+    impl Shape for Square {
+        fn name(&self) -> String {
+            format!("Square({})", self.side)
+        }
+        
+        fn area(&self) -> f64 {
+            self.side * self.side
+        }
+        
+        fn boxed_clone(&self) -> Box<dyn Shape> {
+            Box::new(self.clone())
+        }
+    }
+    
+    impl Duplicate for Square {
+        fn duplicate(&self) -> Self {
+            self.clone()
+        }
+    }
+    
+    let shapes: Vec<Box<dyn Shape>> = vec![Box::new(Square { side: 2.0 }), Box::new(Square { side: 3.0 })];
+    for shape in &shapes {
+        let copy = shape.boxed_clone();
+        println!("{} has area {:.1} (clone: {})", shape.name(), shape.area(), copy.name());
+    }
+    
+    // Duplicate still works with static dispatch
+    let original = Square { side: 4.0 };
+    println!("Duplicated via generics: {}", original.duplicate().name());
+    
+    // let dup: Box<dyn Duplicate> = Box::new(original); // This would cause a compile error!
+    
+    // The rules, roughly: a trait is object safe if its methods
+    //   - take a receiver (&self, &mut self, self: Box<Self>, ...)
+    //   - don't return Self or use Self by value
+    //   - have no generic type parameters
+    //   - and the trait doesn't require Self: Sized
+    // Methods that break a rule can opt out with `where Self: Sized`
+    println!("Rule of thumb: no Self by value and no generic methods in the vtable");
+    println!();
+}
+
+/// Demo 4j: Custom Ord - Hand-written comparisons for sorting
+pub fn demo_custom_ord() {
+    println!("=== Demo 4j: Custom Ord and Sorting ===");
+    
+    use std::cmp::Ordering;
+    
+    #[derive(Debug, PartialEq, Eq)]
+    struct Player {
+        name: String,
+        team: String,
+        score: u32,
+    }
+    
+    // Sort by team ascending, then by score descending within a team.
+    // Ord must agree with Eq: cmp may return Equal only when the values are ==,
+    // and the derived Eq also compares name, so name breaks any remaining tie
+    impl Ord for Player {
+        fn cmp(&self, other: &Self) -> Ordering {
+            self.team
+                .cmp(&other.team)
+                .then_with(|| other.score.cmp(&self.score))
+                .then_with(|| self.name.cmp(&other.name))
+        }
+    }
+    
+    // PartialOrd should agree with Ord, so just delegate
+    impl PartialOrd for Player {
+        fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+            Some(self.cmp(other))
+        }
+    }
+    
+    fn player(name: &str, team: &str, score: u32) -> Player {
+        Player { name: name.to_string(), team: team.to_string(), score }
+    }
+    
+    let mut players = vec![
+        player("Dana", "red", 30),
+        player("Ali", "blue", 15),
+        player("Cy", "red", 45),
+        player("Bo", "blue", 50),
+    ];
+    
+    players.sort();
+    println!("sort() with custom Ord (team asc, score desc, name asc):");
+    for p in &players {
+        println!("  {:<5} {:<5} {}", p.team, p.name, p.score);
+    }
+    
+    // sort_by_key for a one-off ordering by a single field
+    players.sort_by_key(|p| p.name.clone());
+    let names: Vec<&str> = players.iter().map(|p| p.name.as_str()).collect();
+    println!("sort_by_key(name): {:?}", names);
+    
+    // sort_by with a closure for anything else, here highest score first
+    // with ties broken by name
+    players.sort_by(|a, b| b.score.cmp(&a.score).then(a.name.cmp(&b.name)));
+    let scores: Vec<(&str, u32)> = players.iter().map(|p| (p.name.as_str(), p.score)).collect();
+    println!("sort_by(score desc): {:?}", scores);
+    
+    // Ord also powers max/min
+    players.sort();
+    println!("max() by custom Ord: {:?}", players.iter().max().map(|p| &p.name));
+    println!();
+}
+
+/// Demo 4k: More Operators - Sub, Mul, Neg, compound assignment and Index
+pub fn demo_more_operators() {
+    println!("=== Demo 4k: More Operator Overloading ===");
+    
+    use std::ops::{AddAssign, Index, Mul, MulAssign, Neg, Sub};
+    
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    struct Vector {
+        x: f64,
+        y: f64,
+    }
+    
+    impl Sub for Vector {
+        type Output = Vector;
+        
+        fn sub(self, other: Vector) -> Vector {
+            Vector { x: self.x - other.x, y: self.y - other.y }
+        }
+    }
+    
+    // The right-hand side doesn't have to be the same type
+    impl Mul<f64> for Vector {
+        type Output = Vector;
+        
+        fn mul(self, factor: f64) -> Vector {
+            Vector { x: self.x * factor, y: self.y * factor }
+        }
+    }
+    
+    impl Neg for Vector {
+        type Output = Vector;
+        
+        fn neg(self) -> Vector {
+            Vector { x: -self.x, y: -self.y }
+        }
+    }
+    
+    // Compound assignment traits take &mut self and return nothing
+    impl AddAssign for Vector {
+        fn add_assign(&mut self, other: Vector) {
+            self.x += other.x;
+            self.y += other.y;
+        }
+    }
+    
+    impl MulAssign<f64> for Vector {
+        fn mul_assign(&mut self, factor: f64) {
+            self.x *= factor;
+            self.y *= factor;
+        }
+    }
+    
+    let a = Vector { x: 3.0, y: 4.0 };
+    let b = Vector { x: 1.0, y: 2.0 };
+    println!("a - b = {:?}", a - b);
+    println!("a * 1.5 = {:?}", a * 1.5);
+    println!("-a = {:?}", -a);
+    
+    let mut v = a;
+    v += b;
+    println!("v += b -> {:?}", v);
+    v *= 2.0;
+    println!("v *= 2.0 -> {:?}", v);
+    
+    // Index lets a wrapper type use [] with any key type, here a (row, col) tuple
+    struct Matrix {
+        cols: usize,
+        data: Vec<i32>,
+    }
+    
+    impl Index<(usize, usize)> for Matrix {
+        type Output = i32;
+        
+        fn index(&self, (row, col): (usize, usize)) -> &i32 {
+            &self.data[row * self.cols + col]
+        }
+    }
+    
+    let m = Matrix { cols: 3, data: vec![1, 2, 3, 4, 5, 6] };
+    println!("m[(0,1)] = {}", m[(0, 1)]);
+    println!("m[(1,2)] = {}", m[(1, 2)]);
+    println!();
+}
+
+/// Demo 4l: Generic Ordering - Comparison bounds in generic functions
+pub fn demo_generic_ordering() {
+    println!("=== Demo 4l: Generic Ordering ===");
+    
+    // PartialOrd gives us `>`; Copy lets us return the element by value
+    fn largest<T: PartialOrd + Copy>(items: &[T]) -> T {
+        let mut largest = items[0];
+        for &item in items {
+            if item > largest {
+                largest = item;
+            }
+        }
+        largest
+    }
+    
+    // Ord is a total order, so min/max always have an answer
+    fn clamp_generic<T: Ord>(v: T, lo: T, hi: T) -> T {
+        if v < lo {
+            lo
+        } else if v > hi {
+            hi
+        } else {
+            v
+        }
+    }
+    
+    let numbers = [34, 50, 25, 100, 65];
+    let chars = ['y', 'm', 'a', 'q'];
+    let floats = [1.5, -0.25, 9.75, 3.0];
+    
+    println!("largest({:?}) = {}", numbers, largest(&numbers));
+    println!("largest({:?}) = {:?}", chars, largest(&chars));
+    // f64 is only PartialOrd (NaN isn't comparable), which is all largest() asks for
+    println!("largest({:?}) = {}", floats, largest(&floats));
+    
+    println!("clamp_generic(150, 0, 100) = {}", clamp_generic(150, 0, 100));
+    println!("clamp_generic(-7, 0, 100) = {}", clamp_generic(-7, 0, 100));
+    println!("clamp_generic('z', 'a', 'm') = {:?}", clamp_generic('z', 'a', 'm'));
+    println!("clamp_generic(\"kiwi\", \"apple\", \"fig\") = {:?}", clamp_generic("kiwi", "apple", "fig"));
+    
+    // clamp_generic(2.5, 0.0, 1.0); // This would cause a compile error! f64 is not Ord
+    println!();
+}
+
+/// Demo 4m: PhantomData - Zero-sized type tags for units
+pub fn demo_phantom_data() {
+    println!("=== Demo 4m: PhantomData ===");
+    
+    use std::marker::PhantomData;
+    use std::ops::Add;
+    
+    // Marker types: no fields, they only exist at compile time
+    #[derive(Debug, Clone, Copy)]
+    struct Meters;
+    #[derive(Debug, Clone, Copy)]
+    struct Feet;
+    
+    // PhantomData<Unit> "uses" the type parameter without storing a value
+    #[derive(Debug, Clone, Copy)]
+    struct Measurement<Unit> {
+        value: f64,
+        _unit: PhantomData<Unit>,
+    }
+    
+    impl<Unit> Measurement<Unit> {
+        fn new(value: f64) -> Self {
+            Measurement { value, _unit: PhantomData }
+        }
+    }
+    
+    // Only same-unit addition is defined
+    impl<Unit> Add for Measurement<Unit> {
+        type Output = Measurement<Unit>;
+        fn add(self, other: Self) -> Self::Output {
+            Measurement::new(self.value + other.value)
+        }
+    }
+    
+    // Conversion is explicit and changes the type
+    impl Measurement<Feet> {
+        fn to_meters(self) -> Measurement<Meters> {
+            Measurement::new(self.value * 0.3048)
+        }
+    }
+    
+    let track: Measurement<Meters> = Measurement::new(400.0);
+    let sprint: Measurement<Meters> = Measurement::new(100.0);
+    let runway: Measurement<Feet> = Measurement::new(9000.0);
+    
+    let total = track + sprint;
+    println!("400 m + 100 m = {} m", total.value);
+    
+    // let oops = track + runway; // This would cause a compile error! Meters + Feet
+    
+    let combined = track + runway.to_meters();
+    println!("400 m + 9000 ft = {:.1} m (after an explicit to_meters())", combined.value);
+    
+    println!("Size of Measurement<Meters>: {} bytes (same as f64: {})",
+             std::mem::size_of::<Measurement<Meters>>(), std::mem::size_of::<f64>());
+    println!();
+}
+
+/// Demo 4n: Downcasting - Recovering concrete types from trait objects
+pub fn demo_any_downcast() {
+    println!("=== Demo 4n: Downcasting ===");
+    
+    use std::any::Any;
+    
+    // A supertrait of Any lets every Shape be viewed as &dyn Any
+    trait Shape: Any {
+        fn name(&self) -> &'static str;
+        fn area(&self) -> f64;
+    }
+    
+    struct Rectangle {
+        width: f64,
+        height: f64,
+    }
+    
+    struct Circle {
+        radius: f64,
+    }
+    
+    impl Shape for Rectangle {
+        fn name(&self) -> &'static str { "Rectangle" }
+        fn area(&self) -> f64 { self.width * self.height }
+    }
+    
+    impl Shape for Circle {
+        fn name(&self) -> &'static str { "Circle" }
+        fn area(&self) -> f64 { std::f64::consts::PI * self.radius * self.radius }
+    }
+    
+    let shapes: Vec<Box<dyn Shape>> = vec![
+        Box::new(Circle { radius: 1.0 }),
+        Box::new(Rectangle { width: 4.0, height: 4.0 }),
+        Box::new(Rectangle { width: 2.0, height: 3.0 }),
+        Box::new(Circle { radius: 0.5 }),
+    ];
+    
+    for (i, shape) in shapes.iter().enumerate() {
+        // Upcast &dyn Shape to &dyn Any (Rust 1.86+); note the deref, since
+        // `shape as &dyn Any` would view the Box itself, not what's inside
+        let any: &dyn Any = &**shape;
+        match any.downcast_ref::<Rectangle>() {
+            Some(rect) if rect.width == rect.height => {
+                println!("  [{}] Rectangle recognized: {}x{}, and it's a square!", i, rect.width, rect.height);
+            }
+            Some(rect) => {
+                println!("  [{}] Rectangle recognized: {}x{}", i, rect.width, rect.height);
+            }
+            None => println!("  [{}] {} handled generically, area {:.2}", i, shape.name(), shape.area()),
+        }
+    }
+    
+    // Box<dyn Any> holds values with no common trait at all
+    let bag: Vec<Box<dyn Any>> = vec![Box::new(42_i32), Box::new("hello"), Box::new(Rectangle { width: 1.0, height: 2.0 })];
+    let recognized = bag.iter().filter(|item| item.is::<Rectangle>()).count();
+    println!("Box<dyn Any> bag: {} of {} items are Rectangles", recognized, bag.len());
+    if let Some(n) = bag[0].downcast_ref::<i32>() {
+        println!("  bag[0] is an i32: {}", n);
+    }
+    // downcast_ref to the wrong type just returns None
+    println!("  bag[1] as i32: {:?}", bag[1].downcast_ref::<i32>());
+    println!();
+}
+
+/// Demo 4o: Memoize - A generic struct that caches a closure's results
+pub fn demo_memoize() {
+    println!("=== Demo 4o: Memoize ===");
+    use std::collections::HashMap;
+    
+    // Generic over the closure type, so each Cacher is monomorphized (no Box, no dyn)
+    struct Cacher<F: Fn(u32) -> u32> {
+        calc: F,
+        cache: HashMap<u32, u32>,
+    }
+    
+    impl<F: Fn(u32) -> u32> Cacher<F> {
+        fn new(calc: F) -> Self {
+            Cacher { calc, cache: HashMap::new() }
+        }
+        
+        fn value(&mut self, n: u32) -> u32 {
+            if let Some(&cached) = self.cache.get(&n) {
+                println!("  value({}) = {} (from cache)", n, cached);
+                return cached;
+            }
+            let result = (self.calc)(n);
+            println!("  value({}) = {} (computed)", n, result);
+            self.cache.insert(n, result);
+            result
+        }
+    }
+    
+    // A deliberately slow calculation: sum of all divisors of n
+    let mut divisor_sum = Cacher::new(|n| (1..=n).filter(|d| n % d == 0).sum());
+    for n in [12, 28, 12, 6, 28, 12] {
+        divisor_sum.value(n);
+    }
+    println!("Cached entries: {}", divisor_sum.cache.len());
+    
+    // Any Fn(u32) -> u32 works, including closures that capture
+    let base: u32 = 3;
+    let mut power = Cacher::new(move |exp| base.pow(exp));
+    power.value(4);
+    power.value(4);
+    println!();
+}
+
+/// Header printed by `run_all_demos`
+pub const BANNER: &str = concat!(crab!(), " RUST LECTURE - SECTION 4: TRAIT SYSTEM AND GENERICS ", crab!());
+
+/// Concepts listed in the recap printed after `run_all_demos`
+pub const RECAP: &[&str] = &[
+    "Traits define shared behavior; generics reuse it",
+    "Trait bounds say what a generic type must support",
+    "dyn Trait gives dynamic dispatch for object-safe traits",
+    "Associated types and operator traits shape APIs",
+    "PhantomData tags types at zero runtime cost",
+    "Any downcasts a trait object to its concrete type",
+];
+
 /// Run all demos in sequence
 pub fn run_all_demos() {
-    println!("🦀 RUST LECTURE - SECTION 4: TRAIT SYSTEM AND GENERICS 🦀");
+    println!("{}", BANNER);
     println!("===========================================================");
     println!();
     
@@ -355,9 +856,17 @@ pub fn run_all_demos() {
     demo_associated_types();
     demo_operator_overloading();
     demo_standard_traits();
-    
-    println!("✅ Section 4 complete!");
-    println!("💡 Key takeaway: Traits enable zero-cost abstractions and code reuse!");
+    demo_generic_methods();
+    demo_object_safety();
+    demo_custom_ord();
+    demo_more_operators();
+    demo_generic_ordering();
+    demo_phantom_data();
+    demo_any_downcast();
+    demo_memoize();
+    
+    println!(concat!(check!(), " Section 4 complete!"));
+    crate::lecture_utils::print_recap("Section 4: Traits and Generics", RECAP);
 }
 
 /// Get list of available demos for enhanced navigation
@@ -370,5 +879,13 @@ pub fn get_demo_list() -> Vec<&'static str> {
         "associated",
         "operators",
         "standard",
+        "generic_methods",
+        "object_safety",
+        "custom_ord",
+        "more_operators",
+        "generic_ordering",
+        "phantom",
+        "downcast",
+        "memoize",
     ]
 }
\ No newline at end of file
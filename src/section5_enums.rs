@@ -83,6 +83,7 @@ pub fn demo_enums_with_data() {
 }
 
 /// Demo 5c: Option<T> - Handling the absence of values safely
+#[allow(clippy::unnecessary_literal_unwrap)]
 pub fn demo_option_type() {
     println!("=== Demo 5c: Option<T> ===");
     
@@ -351,6 +352,7 @@ pub fn demo_error_propagation() {
     use std::io::{self, Read};
     
     // Manual error handling
+    #[allow(clippy::question_mark)]
     fn read_username_manual() -> Result<String, io::Error> {
         let mut file = match File::open("username.txt") {
             Ok(file) => file,
@@ -415,9 +417,373 @@ pub fn demo_error_propagation() {
     println!();
 }
 
+/// Demo 5h: Result Combinators - Chaining fallible steps without match
+pub fn demo_result_combinators() {
+    println!("=== Demo 5h: Result Combinators ===");
+    
+    // parse -> validate -> transform, each step may fail
+    fn parse_age(input: &str) -> Result<String, String> {
+        input
+            .trim()
+            .parse::<u32>()
+            .map_err(|e| format!("'{}' is not a number ({})", input, e))
+            .and_then(|age| {
+                if age <= 150 {
+                    Ok(age)
+                } else {
+                    Err(format!("{} is not a realistic age", age))
+                }
+            })
+            .map(|age| format!("{} years ({} months)", age, age * 12))
+    }
+    
+    for input in ["42", "abc", "200"] {
+        match parse_age(input) {
+            Ok(description) => println!("parse_age({:?}) -> Ok: {}", input, description),
+            Err(error) => println!("parse_age({:?}) -> Err: {}", input, error),
+        }
+    }
+    
+    // Result -> Option: ok() throws the error away
+    let maybe_number: Option<i32> = "17".parse::<i32>().ok();
+    let no_number: Option<i32> = "x".parse::<i32>().ok();
+    println!("\"17\".parse().ok() = {:?}", maybe_number);
+    println!("\"x\".parse().ok() = {:?}", no_number);
+    
+    // Option -> Result: ok_or() supplies the missing error
+    let names = ["alice", "bob"];
+    let found: Result<&&str, &str> = names.iter().find(|n| n.starts_with('b')).ok_or("no name starting with b");
+    let missing: Result<&&str, &str> = names.iter().find(|n| n.starts_with('z')).ok_or("no name starting with z");
+    println!("find 'b' -> {:?}", found);
+    println!("find 'z' -> {:?}", missing);
+    
+    // unwrap_or_else computes a fallback only when needed
+    let description = parse_age("oops").unwrap_or_else(|e| format!("fallback used because: {}", e));
+    println!("{}", description);
+    println!();
+}
+
+/// Demo 5i: ? in main - Returning Result<(), Box<dyn Error>> from the top
+pub fn demo_main_question_mark() {
+    println!("=== Demo 5i: ? in main ===");
+    
+    use std::error::Error;
+    use std::path::Path;
+    
+    // This has the same shape as `fn main() -> Result<(), Box<dyn Error>>`.
+    // Box<dyn Error> implements From for any error type, so ? converts
+    // both io::Error and ParseIntError without a custom enum.
+    fn run(path: &Path) -> Result<(), Box<dyn Error>> {
+        let content = std::fs::read_to_string(path)?; // io::Error
+        let count: u32 = content.trim().parse()?;     // ParseIntError
+        println!("  Read count {} from {}", count, path.display());
+        Ok(())
+    }
+    
+    // A fresh temp directory per run, removed when `dir` is dropped
+    let dir = tempfile::tempdir().unwrap();
+    let good = dir.path().join("count_ok.txt");
+    let bad = dir.path().join("count_bad.txt");
+    std::fs::write(&good, "42\n").unwrap();
+    std::fs::write(&bad, "forty-two\n").unwrap();
+    let missing = dir.path().join("does_not_exist.txt");
+    
+    for path in [&good, &bad, &missing] {
+        match run(path) {
+            Ok(()) => println!("run() succeeded"),
+            Err(error) => println!("run() failed: {}", error),
+        }
+    }
+    
+    // When main itself returns Err, Rust prints the error's Debug output
+    // and the process exits with a non-zero status code.
+    println!("? works in any function whose return type can absorb the error");
+    println!();
+}
+
+/// Demo 5j: Non-exhaustive Enums - Leaving room for future variants
+pub fn demo_non_exhaustive() {
+    println!("=== Demo 5j: Non-exhaustive Enums ===");
+    
+    // A library marks an enum #[non_exhaustive] so that adding a variant
+    // later isn't a breaking change for the crates that match on it
+    #[derive(Debug)]
+    #[non_exhaustive]
+    pub enum ApiError {
+        NotFound,
+        Unauthorized,
+        RateLimited { retry_after_secs: u64 },
+        Timeout,
+    }
+    
+    fn describe(error: &ApiError) -> String {
+        match error {
+            ApiError::NotFound => "resource not found".to_string(),
+            ApiError::Unauthorized => "please log in".to_string(),
+            ApiError::RateLimited { retry_after_secs } => {
+                format!("slow down, retry in {}s", retry_after_secs)
+            }
+            // Timeout lands here. In another crate the compiler requires this
+            // arm even when every current variant is listed, because new ones
+            // may appear later. (Inside the defining crate it's optional.)
+            _ => "unexpected API error".to_string(),
+        }
+    }
+    
+    let errors = [
+        ApiError::NotFound,
+        ApiError::Unauthorized,
+        ApiError::RateLimited { retry_after_secs: 30 },
+        ApiError::Timeout,
+    ];
+    
+    for error in &errors {
+        println!("{:?} -> {}", error, describe(error));
+    }
+    
+    // std uses this too: io::ErrorKind is #[non_exhaustive]
+    let kind = std::io::ErrorKind::NotFound;
+    let message = match kind {
+        std::io::ErrorKind::NotFound => "file missing",
+        std::io::ErrorKind::PermissionDenied => "no permission",
+        _ => "some other I/O problem", // required: ErrorKind is non_exhaustive
+    };
+    println!("io::ErrorKind::NotFound -> {}", message);
+    println!();
+}
+
+/// Demo 5k: State Machine - Enums and match as a finite state machine
+pub fn demo_state_machine() {
+    println!("=== Demo 5k: State Machine ===");
+    
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    enum State {
+        Idle,
+        Running,
+        Paused,
+        Stopped,
+    }
+    
+    #[derive(Debug, Clone, Copy)]
+    enum Event {
+        Start,
+        Pause,
+        Resume,
+        Stop,
+        Reset,
+    }
+    
+    // Every legal (state, event) pair is listed; anything else keeps the old state
+    fn transition(state: State, event: Event) -> State {
+        match state {
+            State::Idle => match event {
+                Event::Start => State::Running,
+                _ => reject(state, event),
+            },
+            State::Running => match event {
+                Event::Pause => State::Paused,
+                Event::Stop => State::Stopped,
+                _ => reject(state, event),
+            },
+            State::Paused => match event {
+                Event::Resume => State::Running,
+                Event::Stop => State::Stopped,
+                _ => reject(state, event),
+            },
+            State::Stopped => match event {
+                Event::Reset => State::Idle,
+                _ => reject(state, event),
+            },
+        }
+    }
+    
+    fn reject(state: State, event: Event) -> State {
+        println!("    ⚠️  {:?} is not allowed while {:?}, ignoring", event, state);
+        state
+    }
+    
+    // matches! turns a pattern into a bool, handy for grouping states
+    fn is_active(state: State) -> bool {
+        matches!(state, State::Running | State::Paused)
+    }
+    
+    let events = [
+        Event::Pause, // illegal: nothing to pause yet
+        Event::Start,
+        Event::Pause,
+        Event::Start, // illegal: a paused machine resumes instead
+        Event::Resume,
+        Event::Stop,
+        Event::Resume, // illegal: stopped machines can't resume
+        Event::Reset,
+    ];
+    
+    let mut state = State::Idle;
+    println!("Start: {:?}", state);
+    for event in events {
+        state = transition(state, event);
+        println!("  {:<7} -> {:<8} (active: {})", format!("{:?}", event), format!("{:?}", state), is_active(state));
+    }
+    println!();
+}
+
+/// Demo 5l: Tagged JSON - Deserializing wire commands straight into an enum
+pub fn demo_tagged_json() {
+    println!("=== Demo 5l: Tagged JSON ===");
+    use serde::Deserialize;
+    
+    // The same shape as Message from Demo 5b, but read from JSON.
+    // Internally tagged enums need named fields, so Write and ChangeColor use structs
+    #[derive(Deserialize, Debug)]
+    #[serde(tag = "type", rename_all = "snake_case")]
+    enum Command {
+        Quit,
+        Move { x: i32, y: i32 },
+        Write { text: String },
+        ChangeColor { r: u8, g: u8, b: u8 },
+    }
+    
+    fn dispatch(command: Command) {
+        match command {
+            Command::Quit => println!("    Quitting application"),
+            Command::Move { x, y } => println!("    Moving to ({}, {})", x, y),
+            Command::Write { text } => println!("    Writing: {}", text),
+            Command::ChangeColor { r, g, b } => println!("    Changing color to RGB({}, {}, {})", r, g, b),
+        }
+    }
+    
+    let wire = [
+        r#"{"type":"move","x":1,"y":2}"#,
+        r#"{"type":"write","text":"Hello from the network"}"#,
+        r#"{"type":"change_color","r":255,"g":0,"b":128}"#,
+        r#"{"type":"quit"}"#,
+        r#"{"type":"teleport","x":9}"#,
+        r#"{"type":"move","x":"left"}"#,
+    ];
+    
+    for json in wire {
+        println!("  {}", json);
+        // Unknown tags and wrong field types become errors instead of bad states
+        match serde_json::from_str::<Command>(json) {
+            Ok(command) => dispatch(command),
+            Err(e) => println!("    Rejected: {}", e),
+        }
+    }
+    println!();
+}
+
+/// Demo 5m: Error Boxing - Box<dyn Error> vs a typed error enum
+pub fn demo_error_boxing() {
+    println!("=== Demo 5m: Error Boxing ===");
+    use std::error::Error;
+    use std::fmt;
+    use std::num::{ParseFloatError, ParseIntError};
+    
+    // Box<dyn Error> accepts any error type, so ? needs no From impls at all
+    fn parse_order_boxed(line: &str) -> Result<(u32, f64), Box<dyn Error>> {
+        let (qty, price) = line.split_once(',').ok_or("missing comma")?; // &str -> Box<dyn Error>
+        let qty: u32 = qty.trim().parse()?; // ParseIntError -> Box<dyn Error>
+        let price: f64 = price.trim().parse()?; // ParseFloatError -> Box<dyn Error>
+        Ok((qty, price))
+    }
+    
+    println!("Box<dyn Error>:");
+    for line in ["3, 9.99", "3 9.99", "three, 9.99", "3, cheap"] {
+        match parse_order_boxed(line) {
+            Ok((qty, price)) => println!("  {:<13} -> {} x {:.2}", format!("{:?}", line), qty, price),
+            Err(e) => println!("  {:<13} -> error: {}", format!("{:?}", line), e),
+        }
+    }
+    
+    // Typed alternative: callers can match on variants, at the cost of From impls
+    #[derive(Debug)]
+    enum OrderError {
+        MissingComma,
+        BadQuantity(ParseIntError),
+        BadPrice(ParseFloatError),
+    }
+    
+    impl fmt::Display for OrderError {
+        fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            match self {
+                OrderError::MissingComma => write!(f, "expected \"qty, price\""),
+                OrderError::BadQuantity(_) => write!(f, "quantity is not a whole number"),
+                OrderError::BadPrice(_) => write!(f, "price is not a number"),
+            }
+        }
+    }
+    
+    impl Error for OrderError {
+        // source() exposes the underlying cause so it isn't lost
+        fn source(&self) -> Option<&(dyn Error + 'static)> {
+            match self {
+                OrderError::MissingComma => None,
+                OrderError::BadQuantity(e) => Some(e),
+                OrderError::BadPrice(e) => Some(e),
+            }
+        }
+    }
+    
+    impl From<ParseIntError> for OrderError {
+        fn from(e: ParseIntError) -> Self {
+            OrderError::BadQuantity(e)
+        }
+    }
+    
+    impl From<ParseFloatError> for OrderError {
+        fn from(e: ParseFloatError) -> Self {
+            OrderError::BadPrice(e)
+        }
+    }
+    
+    fn parse_order_typed(line: &str) -> Result<(u32, f64), OrderError> {
+        let (qty, price) = line.split_once(',').ok_or(OrderError::MissingComma)?;
+        Ok((qty.trim().parse()?, price.trim().parse()?))
+    }
+    
+    // A typed error still boxes cleanly, so both styles compose
+    fn load_order(line: &str) -> Result<f64, Box<dyn Error>> {
+        let (qty, price) = parse_order_typed(line)?;
+        Ok(qty as f64 * price)
+    }
+    
+    println!("\nTyped enum, then boxed, walking source():");
+    for line in ["2, 4.50", "two, 4.50", "2, free"] {
+        match load_order(line) {
+            Ok(total) => println!("  {:?} -> total {:.2}", line, total),
+            Err(e) => {
+                println!("  {:?} -> error: {}", line, e);
+                let mut cause = e.source();
+                while let Some(inner) = cause {
+                    println!("      caused by: {}", inner);
+                    cause = inner.source();
+                }
+                // downcast_ref recovers the typed error when a caller needs it
+                if let Some(OrderError::BadPrice(_)) = e.downcast_ref::<OrderError>() {
+                    println!("      (recognized as OrderError::BadPrice)");
+                }
+            }
+        }
+    }
+    println!();
+}
+
+/// Header printed by `run_all_demos`
+pub const BANNER: &str = concat!(crab!(), " RUST LECTURE - SECTION 5: ENUMS, PATTERN MATCHING, OPTION & RESULT ", crab!());
+
+/// Concepts listed in the recap printed after `run_all_demos`
+pub const RECAP: &[&str] = &[
+    "Enum variants can carry different data",
+    "match must be exhaustive; guards refine patterns",
+    "Option replaces null, Result replaces exceptions",
+    "? propagates errors to the caller, even from main",
+    "Combinators chain fallible steps without nesting",
+    "#[non_exhaustive] leaves room for future variants",
+];
+
 /// Run all demos in sequence
 pub fn run_all_demos() {
-    println!("🦀 RUST LECTURE - SECTION 5: ENUMS, PATTERN MATCHING, OPTION & RESULT 🦀");
+    println!("{}", BANNER);
     println!("==============================================================================");
     println!();
     
@@ -428,9 +794,15 @@ pub fn run_all_demos() {
     demo_advanced_patterns();
     demo_recursive_enums();
     demo_error_propagation();
-    
-    println!("✅ Section 5 complete!");
-    println!("💡 Key takeaway: Enums and pattern matching provide safe, expressive error handling!");
+    demo_result_combinators();
+    demo_main_question_mark();
+    demo_non_exhaustive();
+    demo_state_machine();
+    demo_tagged_json();
+    demo_error_boxing();
+    
+    println!(concat!(check!(), " Section 5 complete!"));
+    crate::lecture_utils::print_recap("Section 5: Enums and Pattern Matching", RECAP);
 }
 
 /// Get list of available demos for enhanced navigation
@@ -443,5 +815,11 @@ pub fn get_demo_list() -> Vec<&'static str> {
         "patterns",
         "recursive",
         "propagation",
+        "res_combinators",
+        "main_q",
+        "non_exhaustive",
+        "state_machine",
+        "tagged_json",
+        "error_boxing",
     ]
 }
\ No newline at end of file
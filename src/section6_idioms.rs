@@ -70,6 +70,7 @@ pub fn demo_iterator_patterns() {
 }
 
 /// Demo 6b: Advanced Iterator Techniques
+#[allow(clippy::flat_map_identity)]
 pub fn demo_advanced_iterators() {
     println!("=== Demo 6b: Advanced Iterator Techniques ===");
     
@@ -187,6 +188,7 @@ pub fn demo_error_handling_patterns() {
     }
     
     // Pattern 3: Result combinators
+    #[allow(clippy::unnecessary_literal_unwrap)]
     fn demonstrate_result_combinators() {
         let result: Result<i32, &str> = Ok(42);
         
@@ -334,7 +336,7 @@ pub fn demo_memory_patterns() {
     // Pattern 3: Cow (Clone on Write) for conditional ownership
     use std::borrow::Cow;
     
-    fn process_maybe_modify(input: &str, should_modify: bool) -> Cow<str> {
+    fn process_maybe_modify(input: &str, should_modify: bool) -> Cow<'_, str> {
         if should_modify {
             Cow::Owned(input.to_uppercase())  // Allocate new string
         } else {
@@ -384,6 +386,7 @@ pub fn demo_utility_patterns() {
         retries: u32,
     }
     
+    #[allow(clippy::new_ret_no_self)]
     impl Config {
         fn new() -> ConfigBuilder {
             ConfigBuilder::default()
@@ -503,9 +506,692 @@ pub fn demo_utility_patterns() {
     println!();
 }
 
+/// Demo 6g: Combining Iterators - flatten, chain, rev and cycle
+pub fn demo_iterator_combining() {
+    println!("=== Demo 6g: Combining Iterators ===");
+    
+    // flatten removes one level of nesting
+    let nested = vec![vec![1, 2], vec![3], vec![], vec![4, 5, 6]];
+    let flat: Vec<i32> = nested.into_iter().flatten().collect();
+    println!("flatten: {:?}", flat);
+    
+    // flatten also skips None values in an iterator of Options
+    let maybe = vec![Some(1), None, Some(3)];
+    let present: Vec<i32> = maybe.into_iter().flatten().collect();
+    println!("flatten Options: {:?}", present);
+    
+    // chain joins two iterators end to end
+    let chained: Vec<i32> = (1..=3).chain(10..=12).collect();
+    println!("chain: {:?}", chained);
+    
+    // rev walks a double-ended iterator backwards
+    let countdown: Vec<i32> = (1..=5).rev().collect();
+    println!("rev: {:?}", countdown);
+    
+    // cycle repeats forever, so take() is what makes it finite
+    let pattern: Vec<&str> = ["red", "green", "blue"].iter().copied().cycle().take(7).collect();
+    println!("cycle().take(7): {:?}", pattern);
+    
+    // They compose like any other adapter
+    let combined: Vec<i32> = (1..=3).chain((1..=3).rev()).cycle().take(10).collect();
+    println!("chain + rev + cycle: {:?}", combined);
+    println!();
+}
+
+/// Demo 6h: Cow Normalization - Allocate only when the input must change
+pub fn demo_cow_normalize() {
+    println!("=== Demo 6h: Cow Normalization ===");
+    
+    use std::borrow::Cow;
+    
+    // Trim the ends and collapse runs of spaces, but hand back the
+    // original slice untouched when it's already clean
+    fn normalize(input: &str) -> Cow<'_, str> {
+        let trimmed = input.trim();
+        if !trimmed.contains("  ") {
+            return Cow::Borrowed(trimmed);
+        }
+        
+        let mut output = String::with_capacity(trimmed.len());
+        let mut previous_space = false;
+        for c in trimmed.chars() {
+            if c == ' ' && previous_space {
+                continue;
+            }
+            previous_space = c == ' ';
+            output.push(c);
+        }
+        Cow::Owned(output)
+    }
+    
+    let inputs = ["already clean", "  padded  ", "too   many    spaces", " both  kinds "];
+    
+    for input in inputs {
+        let result = normalize(input);
+        let kind = match &result {
+            Cow::Borrowed(_) => "Borrowed (no allocation)",
+            Cow::Owned(_) => "Owned (allocated)",
+        };
+        println!("{:<24} -> {:<20} {}", format!("{:?}", input), format!("{:?}", result), kind);
+    }
+    
+    // Trimming alone can still borrow: a sub-slice of the input is enough
+    let clean_count = inputs.iter().filter(|s| matches!(normalize(s), Cow::Borrowed(_))).count();
+    println!("{} of {} inputs needed no allocation", clean_count, inputs.len());
+    
+    // into_owned() gives a String either way when you need one
+    let owned: String = normalize("  final   value ").into_owned();
+    println!("into_owned(): {:?}", owned);
+    println!();
+}
+
+/// Demo 6i: Sealed Extension Traits - Adding methods others can't implement
+pub fn demo_sealed_extension() {
+    println!("=== Demo 6i: Sealed Extension Traits ===");
+    
+    mod text_ext {
+        // `private` is not reachable from outside `text_ext`, so nobody
+        // else can name Sealed, and therefore nobody else can implement TextExt
+        mod private {
+            pub trait Sealed {}
+            impl Sealed for str {}
+            impl Sealed for String {}
+        }
+        
+        pub trait TextExt: private::Sealed {
+            fn shout(&self) -> String;
+            fn initials(&self) -> String;
+        }
+        
+        impl TextExt for str {
+            fn shout(&self) -> String {
+                format!("{}!", self.to_uppercase())
+            }
+            
+            fn initials(&self) -> String {
+                self.split_whitespace()
+                    .filter_map(|word| word.chars().next())
+                    .collect()
+            }
+        }
+        
+        impl TextExt for String {
+            fn shout(&self) -> String {
+                self.as_str().shout()
+            }
+            
+            fn initials(&self) -> String {
+                self.as_str().initials()
+            }
+        }
+    }
+    
+    use text_ext::TextExt;
+    
+    // Extension methods read like built-in ones on standard types
+    let phrase = "rust is fun";
+    println!("{:?}.shout() = {:?}", phrase, phrase.shout());
+    
+    let name = String::from("Grace Brewster Hopper");
+    println!("{:?}.initials() = {:?}", name, name.initials());
+    
+    // struct Mine;
+    // impl TextExt for Mine {} // This would cause a compile error! (Sealed is private)
+    
+    // Why seal? The library can add methods to TextExt later without
+    // breaking anyone, since no outside type could be missing them.
+    println!("Sealed traits can grow new methods without breaking downstream crates");
+    println!();
+}
+
+/// Demo 6j: String Splitting - split, splitn, rsplit and friends
+pub fn demo_string_splitting() {
+    println!("=== Demo 6j: String Splitting ===");
+    
+    // split on a delimiter: every field, including empty ones
+    let row = "alice,30,,london";
+    let fields: Vec<&str> = row.split(',').collect();
+    println!("split(','): {:?}", fields);
+    
+    // splitn stops after n pieces, so '=' inside the value survives
+    let setting = "database_url=postgres://user:pw@host/db?sslmode=require";
+    let parts: Vec<&str> = setting.splitn(2, '=').collect();
+    println!("splitn(2, '='): {:?}", parts);
+    
+    // rsplit walks from the end: the first item is the file name
+    let path = "/home/ferris/projects/lecture/src/main.rs";
+    let basename = path.rsplit('/').next().unwrap();
+    let parent_dir = path.rsplit('/').nth(1).unwrap();
+    println!("rsplit('/'): basename = {:?}, parent = {:?}", basename, parent_dir);
+    
+    // split_terminator ignores a trailing delimiter instead of yielding ""
+    let statements = "let a = 1;let b = 2;";
+    let with_split: Vec<&str> = statements.split(';').collect();
+    let with_terminator: Vec<&str> = statements.split_terminator(';').collect();
+    println!("split(';'):            {:?}", with_split);
+    println!("split_terminator(';'): {:?}", with_terminator);
+    
+    // lines() handles both \n and \r\n endings
+    let csv = "name,age\r\nbob,25\r\ncarol,41\n";
+    for (i, line) in csv.lines().enumerate() {
+        let columns: Vec<&str> = line.split(',').collect();
+        println!("  line {}: {:?}", i, columns);
+    }
+    
+    // A char predicate works as a pattern too
+    let messy = "one; two,three  four";
+    let words: Vec<&str> = messy
+        .split(|c: char| c == ';' || c == ',' || c.is_whitespace())
+        .filter(|s| !s.is_empty())
+        .collect();
+    println!("split(closure): {:?}", words);
+    println!();
+}
+
+/// Demo 6k: Entry API - Counting and grouping with HashMap::entry
+pub fn demo_entry_api() {
+    println!("=== Demo 6k: Entry API ===");
+    use std::collections::HashMap;
+    
+    // Word frequencies: insert 0 the first time, then bump in place
+    let text = "the quick brown fox jumps over the lazy dog the end";
+    let mut freq: HashMap<&str, usize> = HashMap::new();
+    for word in text.split_whitespace() {
+        *freq.entry(word).or_insert(0) += 1;
+    }
+    
+    // HashMap order is random, so sort before printing
+    let mut counts: Vec<(&str, usize)> = freq.into_iter().collect();
+    counts.sort_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(b.0)));
+    println!("Word frequencies:");
+    for (word, count) in &counts {
+        println!("  {:<6} {}", word, count);
+    }
+    
+    // Grouping: or_default() creates an empty Vec on first use
+    let students = [("alice", 'A'), ("bob", 'B'), ("carol", 'A'), ("dave", 'C'), ("erin", 'B')];
+    let mut by_grade: HashMap<char, Vec<&str>> = HashMap::new();
+    for (name, grade) in students {
+        by_grade.entry(grade).or_default().push(name);
+    }
+    let mut grades: Vec<_> = by_grade.into_iter().collect();
+    grades.sort();
+    println!("Grouped by grade:");
+    for (grade, names) in &grades {
+        println!("  {}: {:?}", grade, names);
+    }
+    
+    // and_modify runs only when the key exists; or_insert covers the new case
+    let purchases = [("apples", 3), ("pears", 2), ("apples", 5), ("plums", 1), ("pears", 4)];
+    let mut totals: HashMap<&str, (u32, u32)> = HashMap::new();
+    for (item, qty) in purchases {
+        totals
+            .entry(item)
+            .and_modify(|(orders, sum)| {
+                *orders += 1;
+                *sum += qty;
+            })
+            .or_insert((1, qty));
+    }
+    let mut totals: Vec<_> = totals.into_iter().collect();
+    totals.sort();
+    println!("Orders and quantities:");
+    for (item, (orders, sum)) in &totals {
+        println!("  {:<6} {} orders, {} total", item, orders, sum);
+    }
+    println!();
+}
+
+/// Demo 6l: Closures and Fn Traits - Fn, FnMut, FnOnce and move
+pub fn demo_closures() {
+    println!("=== Demo 6l: Closures and Fn Traits ===");
+    
+    // Fn: only reads its captures, so it can be called any number of times
+    fn apply_twice(f: impl Fn(i32) -> i32, x: i32) -> i32 {
+        f(f(x))
+    }
+    
+    // FnMut: mutates its captures, so the caller needs a mutable binding
+    fn call_n_times(mut f: impl FnMut(), n: usize) {
+        for _ in 0..n {
+            f();
+        }
+    }
+    
+    // FnOnce: consumes a capture, so it can only be called once
+    fn consume(f: impl FnOnce() -> String) -> String {
+        f()
+    }
+    
+    let offset = 10;
+    let add_offset = |x| x + offset; // borrows `offset` immutably
+    println!("Fn:     apply_twice(add_offset, 1) = {}", apply_twice(add_offset, 1));
+    println!("        offset is still usable: {}", offset);
+    
+    let mut counter = 0;
+    call_n_times(|| counter += 1, 3); // borrows `counter` mutably
+    println!("FnMut:  counter after 3 calls = {}", counter);
+    
+    let greeting = String::from("hello");
+    let shout = move || {
+        let mut s = greeting; // moves the captured String out
+        s.push('!');
+        s.to_uppercase()
+    };
+    println!("FnOnce: {}", consume(shout));
+    // println!("{}", greeting); // This would cause a compile error!
+    // consume(shout);           // So would calling it a second time
+    
+    // move: the closure owns its captures, which lets it outlive the scope
+    fn make_adder(n: i32) -> impl Fn(i32) -> i32 {
+        move |x| x + n
+    }
+    let add5 = make_adder(5);
+    println!("move:   make_adder(5)(3) = {}", add5(3));
+    
+    let names = ["ferris".to_string(), "corro".to_string()];
+    let handle = std::thread::spawn(move || names.len());
+    println!("move:   thread counted {} names it now owns", handle.join().unwrap());
+    
+    // Every Fn is also FnMut and FnOnce, so it fits wherever those are accepted
+    let double = |x: i32| x * 2;
+    call_n_times(|| print!("{} ", double(21)), 2);
+    println!("<- an Fn closure passed as FnMut");
+    println!();
+}
+
+/// Demo 6m: Boxed Closures - Storing closures in vectors and structs
+pub fn demo_boxed_closures() {
+    println!("=== Demo 6m: Boxed Closures ===");
+    
+    // Every closure has its own unnamed type, so a Vec needs a trait object
+    type Step = Box<dyn Fn(i32) -> i32>;
+    let factor = 3;
+    let pipeline: Vec<(&str, Step)> = vec![
+        ("add 1", Box::new(|x| x + 1)),
+        ("times factor", Box::new(move |x| x * factor)),
+        ("square", Box::new(|x| x * x)),
+        ("minus 10", Box::new(|x| x - 10)),
+    ];
+    
+    let mut value = 2;
+    println!("start: {}", value);
+    for (name, step) in &pipeline {
+        value = step(value);
+        println!("  after {:<12} -> {}", name, value);
+    }
+    
+    // The same pipeline folded into a single result
+    let result = pipeline.iter().fold(5, |acc, (_, step)| step(acc));
+    println!("pipeline(5) = {}", result);
+    
+    // A struct field holding a callback that mutates its own state
+    struct Button {
+        label: String,
+        on_click: Box<dyn FnMut() -> u32>,
+    }
+    
+    impl Button {
+        fn click(&mut self) {
+            let clicks = (self.on_click)();
+            println!("  [{}] clicked {} time(s)", self.label, clicks);
+        }
+    }
+    
+    let mut clicks = 0;
+    let mut button = Button {
+        label: "Submit".to_string(),
+        on_click: Box::new(move || {
+            clicks += 1;
+            clicks
+        }),
+    };
+    println!("Callback stored in a struct:");
+    button.click();
+    button.click();
+    button.click();
+    
+    // Swap the behavior at runtime
+    button.label = "Reset".to_string();
+    button.on_click = Box::new(|| 0);
+    button.click();
+    println!();
+}
+
+/// Demo 6n: Aggregations - sum, min, max and friends over structs
+pub fn demo_aggregations() {
+    println!("=== Demo 6n: Aggregations ===");
+    
+    #[derive(Debug)]
+    struct Employee {
+        name: &'static str,
+        age: u32,
+        salary: f64,
+    }
+    
+    let staff = vec![
+        Employee { name: "Alice", age: 34, salary: 98_000.0 },
+        Employee { name: "Bob", age: 27, salary: 72_500.0 },
+        Employee { name: "Carol", age: 45, salary: 121_000.0 },
+        Employee { name: "Dave", age: 27, salary: 68_000.0 },
+    ];
+    
+    // max_by_key needs an Ord key; on ties it returns the *last* maximum
+    let oldest = staff.iter().max_by_key(|e| e.age);
+    println!("Oldest (max_by_key age): {:?}", oldest.map(|e| e.name));
+    
+    // min_by_key returns the *first* minimum on ties
+    let youngest = staff.iter().min_by_key(|e| e.age);
+    println!("Youngest (min_by_key age): {:?}", youngest.map(|e| e.name));
+    
+    // f64 isn't Ord, so compare with min_by and total_cmp
+    let lowest_paid = staff.iter().min_by(|a, b| a.salary.total_cmp(&b.salary));
+    println!("Lowest paid (min_by salary): {:?}", lowest_paid.map(|e| e.name));
+    
+    // sum over a mapped field
+    let payroll: f64 = staff.iter().map(|e| e.salary).sum();
+    let total_age: u32 = staff.iter().map(|e| e.age).sum();
+    println!("Total payroll: {:.2}, total age: {}", payroll, total_age);
+    
+    // fold carries (sum, count) in one pass, then average if count > 0
+    fn average_salary(people: &[Employee]) -> Option<f64> {
+        let (sum, count) = people.iter().fold((0.0, 0), |(sum, count), e| (sum + e.salary, count + 1));
+        if count == 0 { None } else { Some(sum / count as f64) }
+    }
+    println!("Average salary (fold): {:?}", average_salary(&staff));
+    
+    // Empty input: min/max/average become None, but sum is just 0
+    let nobody: Vec<Employee> = Vec::new();
+    println!("\nOn an empty Vec:");
+    println!("  max_by_key: {:?}", nobody.iter().max_by_key(|e| e.age).map(|e| e.name));
+    println!("  min_by:     {:?}", nobody.iter().min_by(|a, b| a.salary.total_cmp(&b.salary)).map(|e| e.name));
+    println!("  sum:        {}", nobody.iter().map(|e| e.age).sum::<u32>());
+    println!("  average:    {:?}", average_salary(&nobody));
+    let oldest_name = nobody.iter().max_by_key(|e| e.age).map_or("nobody", |e| e.name);
+    println!("  map_or fallback: {}", oldest_name);
+    println!();
+}
+
+/// Demo 6o: Vec Mutation - retain, drain, dedup and swap_remove
+pub fn demo_vec_mutation() {
+    println!("=== Demo 6o: Vec Mutation ===");
+    
+    let mut scores = vec![72, 95, 41, 88, 59, 100, 33, 67];
+    println!("Start:              {:?}", scores);
+    
+    // retain keeps elements where the predicate is true, in place and in order
+    scores.retain(|&s| s >= 50);
+    println!("retain(>= 50):      {:?}", scores);
+    
+    // drain removes a range and hands it back as an iterator
+    let removed: Vec<i32> = scores.drain(1..3).collect();
+    println!("drain(1..3):        {:?} (removed {:?})", scores, removed);
+    
+    // dedup only removes *consecutive* duplicates, so sort first for uniqueness
+    let mut readings = vec![3, 3, 5, 5, 5, 3, 8, 8, 1];
+    println!("\nReadings:           {:?}", readings);
+    readings.dedup();
+    println!("dedup():            {:?}", readings);
+    readings.sort_unstable();
+    readings.dedup();
+    println!("sort + dedup():     {:?}", readings);
+    
+    // dedup_by_key collapses runs that share a key
+    let mut words = vec!["apple", "avocado", "banana", "blueberry", "cherry", "apricot"];
+    words.dedup_by_key(|w| w.chars().next());
+    println!("dedup_by_key(first letter): {:?}", words);
+    
+    // remove shifts everything after the index: O(n), order preserved
+    // swap_remove moves the last element into the hole: O(1), order not preserved
+    let mut queue = vec!['a', 'b', 'c', 'd', 'e'];
+    println!("\nQueue:              {:?}", queue);
+    let r = queue.remove(1);
+    println!("remove(1)      -> {:?}, now {:?}", r, queue);
+    let s = queue.swap_remove(0);
+    println!("swap_remove(0) -> {:?}, now {:?}", s, queue);
+    
+    // truncate and clear drop elements while keeping the allocation
+    queue.truncate(1);
+    println!("truncate(1):        {:?} (capacity {})", queue, queue.capacity());
+    queue.clear();
+    println!("clear():            {:?} (capacity {})", queue, queue.capacity());
+    println!();
+}
+
+/// Demo 6p: VecDeque - Queues and sliding windows
+pub fn demo_vecdeque() {
+    println!("=== Demo 6p: VecDeque ===");
+    use std::collections::VecDeque;
+    
+    // FIFO queue: push at the back, pop from the front, both O(1)
+    let mut jobs: VecDeque<&str> = VecDeque::new();
+    jobs.push_back("compile");
+    jobs.push_back("test");
+    jobs.push_back("deploy");
+    println!("Queue: {:?}", jobs);
+    
+    while let Some(job) = jobs.pop_front() {
+        println!("  Processing {:<8} remaining: {:?}", job, jobs);
+    }
+    
+    // Urgent work can jump the line with push_front
+    jobs.push_back("lint");
+    jobs.push_front("hotfix");
+    println!("After push_front(\"hotfix\"): {:?}", jobs);
+    
+    // Sliding window of the last N readings with a running average
+    const WINDOW: usize = 3;
+    let readings = [10, 20, 30, 40, 50, 60];
+    let mut window: VecDeque<i32> = VecDeque::with_capacity(WINDOW);
+    
+    println!("\nSliding window (size {}):", WINDOW);
+    for reading in readings {
+        window.push_back(reading);
+        if window.len() > WINDOW {
+            window.pop_front();
+        }
+        let average = window.iter().sum::<i32>() as f64 / window.len() as f64;
+        println!("  push {:>2} -> {:?} avg {:.1}", reading, window, average);
+    }
+    
+    // A VecDeque is a ring buffer, so rotating only moves min(n, len - n) elements
+    let mut ring: VecDeque<char> = "abcde".chars().collect();
+    ring.rotate_left(2);
+    println!("\nrotate_left(2): {:?}", ring);
+    println!("front = {:?}, back = {:?}", ring.front(), ring.back());
+    println!();
+}
+
+/// Demo 6q: BTreeMap Navigation - Ordered ranges, first and last
+pub fn demo_btreemap_navigation() {
+    println!("=== Demo 6q: BTreeMap Navigation ===");
+    use std::collections::BTreeMap;
+    
+    // Keys stay sorted, so "between" and "smallest/largest" queries are cheap
+    let mut scores: BTreeMap<u32, &str> = BTreeMap::new();
+    for (score, name) in [(88, "dana"), (42, "eve"), (95, "alice"), (67, "bob"), (73, "carol"), (59, "frank")] {
+        scores.insert(score, name);
+    }
+    println!("All (sorted by key): {:?}", scores);
+    
+    // range() accepts any RangeBounds: a..b, a..=b, ..b, a..
+    let passing: Vec<_> = scores.range(60..).collect();
+    let b_grades: Vec<_> = scores.range(80..90).collect();
+    let below_50: Vec<_> = scores.range(..50).collect();
+    println!("range(60..):   {:?}", passing);
+    println!("range(80..90): {:?}", b_grades);
+    println!("range(..50):   {:?}", below_50);
+    
+    println!("first_key_value(): {:?}", scores.first_key_value());
+    println!("last_key_value():  {:?}", scores.last_key_value());
+    
+    // Nearest key at or below a value: the last entry of ..=target
+    let target = 70;
+    println!("Closest score <= {}: {:?}", target, scores.range(..=target).next_back());
+    
+    // range_mut edits values in place, here crediting a contiguous block of accounts
+    let mut balances: BTreeMap<&str, i64> = [("acct-01", 100), ("acct-02", 250), ("acct-03", 75), ("acct-04", 400)]
+        .into_iter()
+        .collect();
+    for (_, balance) in balances.range_mut("acct-02"..="acct-03") {
+        *balance += 10;
+    }
+    println!("\nAfter range_mut(\"acct-02\"..=\"acct-03\") += 10: {:?}", balances);
+    
+    // pop_first / pop_last make a BTreeMap a simple priority queue
+    let lowest = scores.pop_first();
+    let highest = scores.pop_last();
+    println!("pop_first = {:?}, pop_last = {:?}, remaining {:?}", lowest, highest, scores);
+    println!();
+}
+
+/// Demo 6r: String Conversions - A parse, build and transform cookbook
+pub fn demo_string_conversions() {
+    println!("=== Demo 6r: String Conversions ===");
+    
+    // &str -> String, three equivalent ways
+    let a = String::from("ferris");
+    let b = "ferris".to_string();
+    let c: String = "ferris".into();
+    println!("String::from / to_string / into: {} {} {}", a, b, c);
+    
+    // String -> &str: borrow it
+    let s: &str = a.as_str();
+    let t: &str = &a;
+    println!("as_str / &String: {} {}", s, t);
+    
+    // Numbers -> String
+    println!("42.to_string() = {:?}, format!(\"{{:.2}}\", 2.5) = {:?}", 42.to_string(), format!("{:.2}", 2.5));
+    
+    // String -> numbers: parse returns a Result
+    for input in ["3.75", " 12 ", "abc"] {
+        match input.trim().parse::<f64>() {
+            Ok(n) => println!("parse::<f64>({:?}) = {}", input, n),
+            Err(e) => println!("parse::<f64>({:?}) failed: {}", input, e),
+        }
+    }
+    let port: u16 = "8080".parse().unwrap_or(80);
+    let fallback: u16 = "http".parse().unwrap_or(80);
+    println!("\"8080\" as u16 = {}, \"http\" as u16 with unwrap_or = {}", port, fallback);
+    
+    // Building strings
+    let mut greeting = String::new();
+    greeting.push('H');
+    greeting.push_str("ello");
+    let greeting = greeting + ", " + "world"; // + takes ownership of the left side
+    println!("push / push_str / +: {}", greeting);
+    
+    // Transforming
+    println!("chars().rev(): {}", "stressed".chars().rev().collect::<String>());
+    println!("to_uppercase / to_lowercase: {} {}", "Rust".to_uppercase(), "Rust".to_lowercase());
+    println!("repeat: {}", "ab".repeat(3));
+    println!("replace: {}", "2025-06-04".replace('-', "/"));
+    
+    // chars <-> String
+    let letters: Vec<char> = "hey".chars().collect();
+    let back: String = letters.iter().collect();
+    println!("chars -> Vec<char> -> String: {:?} -> {:?}", letters, back);
+    println!();
+}
+
+/// Demo 6s: try_fold - Fallible iteration that stops at the first error
+pub fn demo_try_fold() {
+    println!("=== Demo 6s: try_fold ===");
+    use std::num::ParseIntError;
+    
+    let all_valid = ["10", "20", "30", "40"];
+    let mixed = ["10", "20", "oops", "40", "also bad"];
+    
+    // collect::<Result<Vec<_>, _>>() returns Ok(all values) or the first Err
+    let parse_all = |inputs: &[&str]| -> Result<Vec<i32>, ParseIntError> {
+        inputs.iter().map(|s| s.parse::<i32>()).collect()
+    };
+    println!("collect into Result:");
+    println!("  {:?} -> {:?}", all_valid, parse_all(&all_valid));
+    println!("  {:?} -> {:?}", mixed, parse_all(&mixed));
+    
+    // try_fold sums as it goes and short-circuits on the first Err, without a Vec
+    let sum_all = |inputs: &[&str]| -> Result<i32, String> {
+        inputs.iter().enumerate().try_fold(0, |acc, (i, s)| {
+            println!("    visiting [{}] {:?}", i, s);
+            let n: i32 = s.parse().map_err(|e| format!("item {} ({:?}): {}", i, s, e))?;
+            Ok(acc + n)
+        })
+    };
+    println!("\ntry_fold sum (all valid):");
+    println!("  -> {:?}", sum_all(&all_valid));
+    println!("try_fold sum (mixed) stops before item 3:");
+    println!("  -> {:?}", sum_all(&mixed));
+    
+    // try_fold also works with Option, e.g. checked arithmetic that may overflow
+    let total = [100u8, 100, 50].iter().try_fold(0u8, |acc, &x| acc.checked_add(x));
+    let too_big = [100u8, 100, 100].iter().try_fold(0u8, |acc, &x| acc.checked_add(x));
+    println!("\nchecked_add with try_fold: {:?} and {:?}", total, too_big);
+    println!();
+}
+
+/// Demo 6t: Collect Targets - One iterator, many container types
+pub fn demo_collect_targets() {
+    println!("=== Demo 6t: Collect Targets ===");
+    use std::collections::{BTreeMap, HashMap, HashSet};
+    
+    let words = ["apple", "banana", "apple", "cherry", "banana"];
+    
+    // The target type drives collect(): annotate the binding...
+    let as_vec: Vec<usize> = words.iter().map(|w| w.len()).collect();
+    println!("Vec<usize>:            {:?}", as_vec);
+    
+    // ...or use the turbofish; HashSet drops duplicates
+    let as_set = words.iter().copied().collect::<HashSet<&str>>();
+    let mut unique: Vec<_> = as_set.iter().collect();
+    unique.sort();
+    println!("HashSet<&str>:         {} unique, sorted {:?}", as_set.len(), unique);
+    
+    // Collecting (key, value) tuples builds a map; later duplicates overwrite earlier ones
+    let lengths: HashMap<&str, usize> = words.iter().map(|w| (*w, w.len())).collect();
+    println!("HashMap<&str, usize>:  {} entries, lengths[\"banana\"] = {}", lengths.len(), lengths["banana"]);
+    let ordered: BTreeMap<&str, usize> = words.iter().map(|w| (*w, w.len())).collect();
+    println!("BTreeMap<&str, usize>: {:?}", ordered);
+    
+    // chars collect into a String, and so do &strs (concatenated with no separator)
+    let initials: String = words.iter().filter_map(|w| w.chars().next()).collect();
+    println!("String (from chars):   {:?}", initials);
+    let joined: String = words.iter().copied().collect();
+    println!("String (from &strs):   {:?}", joined);
+    // For a separator, collect owned pieces and join them
+    let sentence = words.iter().map(|w| w.to_uppercase()).collect::<Vec<_>>().join(" ");
+    println!("String (via join):     {:?}", sentence);
+    
+    // Result<Vec<_>, _> collects Ok values or stops at the first Err
+    let numbers = ["1", "2", "3"];
+    let parsed: Result<Vec<u32>, _> = numbers.iter().map(|s| s.parse::<u32>()).collect();
+    println!("Result<Vec<u32>, _>:   {:?}", parsed);
+    let broken = ["1", "two", "3"];
+    let failed = broken.iter().map(|s| s.parse::<u32>()).collect::<Result<Vec<_>, _>>();
+    println!("Result<Vec<u32>, _>:   {:?}", failed);
+    
+    // Option<Vec<_>> works the same way with None
+    let maybe: Option<Vec<char>> = words.iter().map(|w| w.chars().nth(5)).collect();
+    println!("Option<Vec<char>>:     {:?} (\"apple\" has no 6th char)", maybe);
+    println!();
+}
+
+/// Header printed by `run_all_demos`
+pub const BANNER: &str = concat!(crab!(), " RUST LECTURE - SECTION 6: IDIOMATIC PATTERNS & UTILITIES ", crab!());
+
+/// Concepts listed in the recap printed after `run_all_demos`
+pub const RECAP: &[&str] = &[
+    "Iterator adapters are lazy and compose cleanly",
+    "The entry API counts and groups in a single lookup",
+    "Fn, FnMut and FnOnce describe how closures capture",
+    "Box<dyn Fn> stores closures in structs and vectors",
+    "Cow and in-place Vec methods avoid extra allocation",
+    "Sealed traits add methods others can't implement",
+];
+
 /// Run all demos in sequence
 pub fn run_all_demos() {
-    println!("🦀 RUST LECTURE - SECTION 6: IDIOMATIC PATTERNS & UTILITIES 🦀");
+    println!("{}", BANNER);
     println!("================================================================");
     println!();
     
@@ -515,9 +1201,23 @@ pub fn run_all_demos() {
     demo_shadowing_patterns();
     demo_memory_patterns();
     demo_utility_patterns();
-    
-    println!("✅ Section 6 complete!");
-    println!("💡 Key takeaway: Idiomatic Rust emphasizes zero-cost abstractions and memory efficiency!");
+    demo_iterator_combining();
+    demo_cow_normalize();
+    demo_sealed_extension();
+    demo_string_splitting();
+    demo_entry_api();
+    demo_closures();
+    demo_boxed_closures();
+    demo_aggregations();
+    demo_vec_mutation();
+    demo_vecdeque();
+    demo_btreemap_navigation();
+    demo_string_conversions();
+    demo_try_fold();
+    demo_collect_targets();
+    
+    println!(concat!(check!(), " Section 6 complete!"));
+    crate::lecture_utils::print_recap("Section 6: Idiomatic Patterns", RECAP);
 }
 
 /// Get list of available demos for enhanced navigation
@@ -529,5 +1229,19 @@ pub fn get_demo_list() -> Vec<&'static str> {
         "shadowing",
         "memory",
         "utilities",
+        "combining",
+        "cow_normalize",
+        "sealed",
+        "splitting",
+        "entry_api",
+        "closures",
+        "boxed_closures",
+        "aggregations",
+        "vec_mutation",
+        "vecdeque",
+        "btree_nav",
+        "string_conversions",
+        "try_fold",
+        "collect_targets",
     ]
 }
\ No newline at end of file
@@ -294,43 +294,46 @@ pub fn demo_advanced_concurrency() {
     println!();
 }
 
+/// Simple future that completes after a delay (shared by Demos 7e and 7h)
+struct DelayFuture {
+    when: std::time::Instant,
+}
+
+impl DelayFuture {
+    fn new(duration: std::time::Duration) -> Self {
+        DelayFuture {
+            when: std::time::Instant::now() + duration,
+        }
+    }
+}
+
+impl std::future::Future for DelayFuture {
+    type Output = ();
+    
+    fn poll(
+        self: std::pin::Pin<&mut Self>,
+        cx: &mut std::task::Context<'_>,
+    ) -> std::task::Poll<Self::Output> {
+        if std::time::Instant::now() >= self.when {
+            std::task::Poll::Ready(())
+        } else {
+            // A real timer would register the waker with a reactor; we ask to be
+            // polled again right away, which is simple but busy-waits
+            cx.waker().wake_by_ref();
+            std::task::Poll::Pending
+        }
+    }
+}
+
 /// Demo 7e: Async/Await Basics (using tokio-like patterns)
 pub fn demo_async_basics() {
     println!("=== Demo 7e: Async/Await Basics ===");
     
     // Note: This is a simplified demonstration of async concepts
-    // In real code, you'd use tokio or another async runtime
+    // In real code, you'd use tokio or another async runtime (see Demo 7h)
     
-    use std::future::Future;
-    use std::pin::Pin;
-    use std::task::{Context, Poll};
     use std::time::{Duration, Instant};
     
-    // Simple future that completes after a delay
-    struct DelayFuture {
-        when: Instant,
-    }
-    
-    impl DelayFuture {
-        fn new(duration: Duration) -> Self {
-            DelayFuture {
-                when: Instant::now() + duration,
-            }
-        }
-    }
-    
-    impl Future for DelayFuture {
-        type Output = ();
-        
-        fn poll(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<Self::Output> {
-            if Instant::now() >= self.when {
-                Poll::Ready(())
-            } else {
-                Poll::Pending
-            }
-        }
-    }
-    
     // Simulate async function
     async fn async_task(id: u32) -> String {
         println!("Async task {} starting", id);
@@ -391,6 +394,7 @@ pub fn demo_async_basics() {
 }
 
 /// Demo 7f: Thread Safety and Send/Sync Traits
+#[allow(clippy::missing_const_for_thread_local)]
 pub fn demo_thread_safety() {
     println!("=== Demo 7f: Thread Safety and Send/Sync ===");
     
@@ -496,9 +500,716 @@ pub fn demo_thread_safety() {
     println!();
 }
 
+/// Demo 7g: Barrier - Making threads rendezvous at a synchronization point
+pub fn demo_barrier() {
+    println!("=== Demo 7g: Barrier Synchronization ===");
+    
+    use std::sync::{Arc, Barrier};
+    use std::thread;
+    use std::time::Duration;
+    
+    let num_threads = 4;
+    
+    // Every thread must call wait() before any of them can proceed
+    let barrier = Arc::new(Barrier::new(num_threads));
+    
+    let handles: Vec<_> = (0..num_threads).map(|i| {
+        let barrier = Arc::clone(&barrier);
+        thread::spawn(move || {
+            // Phase 1: staggered work so threads arrive at different times
+            println!("Thread {} doing phase 1", i);
+            thread::sleep(Duration::from_millis(100 * (i as u64 + 1)));
+            println!("Thread {} finished phase 1, waiting at barrier", i);
+            
+            // Blocks until all threads have reached this point
+            let result = barrier.wait();
+            
+            // Exactly one thread is elected "leader" when the barrier releases
+            if result.is_leader() {
+                println!("--- All threads reached the barrier (released by thread {}) ---", i);
+            }
+            
+            // Phase 2: no thread gets here until everyone finished phase 1
+            println!("Thread {} doing phase 2", i);
+        })
+    }).collect();
+    
+    for handle in handles {
+        handle.join().unwrap();
+    }
+    
+    println!("No thread started phase 2 before all finished phase 1!");
+    println!();
+}
+
+/// Demo 7h: Real Async - Driving futures with the tokio runtime
+pub fn demo_real_async() {
+    println!("=== Demo 7h: Real Async with Tokio ===");
+    
+    use std::time::{Duration, Instant};
+    use tokio::time::sleep;
+    
+    // Unlike Demo 7e, these futures are actually polled by an executor
+    let rt = tokio::runtime::Runtime::new().unwrap();
+    
+    rt.block_on(async {
+        async fn async_task(id: u32) -> String {
+            println!("Async task {} starting", id);
+            
+            // Yields to the runtime instead of blocking the thread
+            sleep(Duration::from_millis(200)).await;
+            
+            println!("Async task {} completed", id);
+            format!("Result from task {}", id)
+        }
+        
+        // Sequential: the second task doesn't start until the first finishes
+        let start = Instant::now();
+        let result1 = async_task(1).await;
+        let result2 = async_task(2).await;
+        println!("Sequential results: {}, {}", result1, result2);
+        println!("Sequential time: {:?} (two sleeps)", start.elapsed());
+        
+        // Concurrent: tokio::join! polls both futures at the same time
+        let start = Instant::now();
+        let (result1, result2) = tokio::join!(async_task(3), async_task(4));
+        println!("Concurrent results: {}, {}", result1, result2);
+        println!("Concurrent time: {:?} (one sleep)", start.elapsed());
+        
+        // Our hand-written DelayFuture from Demo 7e, now driven by a real runtime
+        let start = Instant::now();
+        DelayFuture::new(Duration::from_millis(100)).await;
+        println!("DelayFuture resolved after {:?}", start.elapsed());
+    });
+    
+    println!();
+}
+
+/// Demo 7i: Deadlock Avoidance - Rust prevents data races, not deadlocks
+pub fn demo_deadlock_avoidance() {
+    println!("=== Demo 7i: Deadlock Avoidance ===");
+    
+    use parking_lot::Mutex;
+    use std::sync::Arc;
+    use std::thread;
+    use std::time::Duration;
+    
+    let account_a = Arc::new(Mutex::new(100));
+    let account_b = Arc::new(Mutex::new(50));
+    
+    // The problem: two threads take the same two locks in OPPOSITE order.
+    // Thread 1 holds A and wants B, thread 2 holds B and wants A - neither can
+    // ever proceed. We use try_lock_for so the demo gives up instead of hanging.
+    println!("Inconsistent lock order (A->B vs B->A):");
+    
+    let (a, b) = (Arc::clone(&account_a), Arc::clone(&account_b));
+    let thread1 = thread::spawn(move || {
+        let _guard_a = a.lock();
+        println!("  Thread 1 locked A, now waiting for B...");
+        thread::sleep(Duration::from_millis(50));  // Give thread 2 time to lock B
+        match b.try_lock_for(Duration::from_millis(200)) {
+            Some(_guard_b) => println!("  Thread 1 got B"),
+            None => println!("  Thread 1 timed out waiting for B (would deadlock!)"),
+        }
+    });
+    
+    let (a, b) = (Arc::clone(&account_a), Arc::clone(&account_b));
+    let thread2 = thread::spawn(move || {
+        let _guard_b = b.lock();
+        println!("  Thread 2 locked B, now waiting for A...");
+        thread::sleep(Duration::from_millis(50));  // Give thread 1 time to lock A
+        match a.try_lock_for(Duration::from_millis(200)) {
+            Some(_guard_a) => println!("  Thread 2 got A"),
+            None => println!("  Thread 2 timed out waiting for A (would deadlock!)"),
+        }
+    });
+    
+    thread1.join().unwrap();
+    thread2.join().unwrap();
+    
+    // The fix: every thread acquires locks in the same global order (A, then B).
+    // Whoever gets A first also gets B; the other simply waits its turn.
+    println!("\nConsistent lock order (always A then B):");
+    
+    fn transfer(from_a: bool, a: &Mutex<i32>, b: &Mutex<i32>, amount: i32) {
+        let mut guard_a = a.lock();
+        let mut guard_b = b.lock();
+        if from_a {
+            *guard_a -= amount;
+            *guard_b += amount;
+        } else {
+            *guard_b -= amount;
+            *guard_a += amount;
+        }
+    }
+    
+    let handles: Vec<_> = [(true, 30), (false, 10)].into_iter().enumerate().map(|(i, (from_a, amount))| {
+        let (a, b) = (Arc::clone(&account_a), Arc::clone(&account_b));
+        thread::spawn(move || {
+            transfer(from_a, &a, &b, amount);
+            let direction = if from_a { "A -> B" } else { "B -> A" };
+            println!("  Thread {} transferred {} ({})", i + 1, amount, direction);
+        })
+    }).collect();
+    
+    for handle in handles {
+        handle.join().unwrap();
+    }
+    
+    println!("  Final balances: A = {}, B = {}", *account_a.lock(), *account_b.lock());
+    println!("Lock ordering is a convention the compiler can't check - document it!");
+    println!();
+}
+
+/// Demo 7j: Worker Pool - A shared job queue with crossbeam channels
+pub fn demo_worker_pool() {
+    println!("=== Demo 7j: Worker Pool ===");
+    
+    use crossbeam::channel::bounded;
+    use std::thread;
+    use std::time::Duration;
+    
+    // Unlike std::sync::mpsc, crossbeam receivers can be cloned, so many
+    // workers can pull from one queue without wrapping it in Arc<Mutex<_>>
+    let (job_tx, job_rx) = bounded::<u32>(4);  // Small buffer applies backpressure
+    let (result_tx, result_rx) = bounded::<(usize, u32, u32)>(16);
+    
+    let num_workers = 3;
+    let mut workers = Vec::new();
+    
+    for worker_id in 0..num_workers {
+        let job_rx = job_rx.clone();
+        let result_tx = result_tx.clone();
+        workers.push(thread::spawn(move || {
+            // recv() returns Err once the channel is empty AND all senders are gone
+            while let Ok(job) = job_rx.recv() {
+                thread::sleep(Duration::from_millis(20 * (job as u64 % 3 + 1)));  // Uneven work
+                result_tx.send((worker_id, job, job * job)).unwrap();
+            }
+            println!("Worker {} shutting down", worker_id);
+        }));
+    }
+    
+    // Drop our copies so the channels close when the workers/producer finish
+    drop(job_rx);
+    drop(result_tx);
+    
+    // Producer: enqueue jobs, then close the queue by dropping the sender
+    let producer = thread::spawn(move || {
+        for job in 1..=12 {
+            job_tx.send(job).unwrap();
+        }
+    });
+    
+    // Collect results as they arrive
+    let mut jobs_per_worker = vec![0; num_workers];
+    for (worker_id, job, result) in result_rx.iter() {
+        println!("Worker {} handled job {:>2} -> {}", worker_id, job, result);
+        jobs_per_worker[worker_id] += 1;
+    }
+    
+    producer.join().unwrap();
+    for worker in workers {
+        worker.join().unwrap();
+    }
+    
+    println!("Load distribution (jobs per worker): {:?}", jobs_per_worker);
+    println!();
+}
+
+/// Demo 7k: Receive with Timeout - Consumers that never hang forever
+pub fn demo_recv_timeout() {
+    println!("=== Demo 7k: Receive with Timeout ===");
+    
+    use std::sync::mpsc::{self, RecvTimeoutError};
+    use std::thread;
+    use std::time::{Duration, Instant};
+    
+    let (tx, rx) = mpsc::channel();
+    
+    // A slow producer that only sends every ~450ms
+    let producer = thread::spawn(move || {
+        for reading in ["23.5°C", "23.7°C", "24.1°C"] {
+            thread::sleep(Duration::from_millis(450));
+            tx.send(reading).unwrap();
+        }
+        // tx is dropped here, which disconnects the channel
+    });
+    
+    let start = Instant::now();
+    loop {
+        match rx.recv_timeout(Duration::from_millis(200)) {
+            Ok(reading) => println!("[{:>4}ms] Received {}", start.elapsed().as_millis(), reading),
+            Err(RecvTimeoutError::Timeout) => {
+                // A real consumer could do housekeeping or check a shutdown flag here
+                println!("[{:>4}ms] Timed out, still waiting...", start.elapsed().as_millis());
+                continue;
+            }
+            Err(RecvTimeoutError::Disconnected) => {
+                println!("[{:>4}ms] Producer finished, channel closed", start.elapsed().as_millis());
+                break;
+            }
+        }
+    }
+    
+    producer.join().unwrap();
+    println!();
+}
+
+/// Demo 7l: Crossbeam Scope - Scoped threads before std::thread::scope
+pub fn demo_crossbeam_scope() {
+    println!("=== Demo 7l: Crossbeam Scope ===");
+    
+    use std::thread;
+    
+    let readings = [3, 8, 1, 9, 4, 7, 2, 6];
+    let (left, right) = readings.split_at(readings.len() / 2);
+    
+    // Scoped threads may borrow local data: the scope can't end until
+    // every thread spawned inside it has been joined
+    let (left_max, right_max) = crossbeam::scope(|s| {
+        let a = s.spawn(|_| *left.iter().max().unwrap());
+        let b = s.spawn(|_| *right.iter().max().unwrap());
+        (a.join().unwrap(), b.join().unwrap())
+    })
+    .unwrap(); // Err if any thread in the scope panicked
+    
+    println!("crossbeam::scope -> max of {:?} = {}, max of {:?} = {}", left, left_max, right, right_max);
+    
+    // Crossbeam closures receive the scope, so threads can spawn
+    // more scoped threads themselves
+    let total = crossbeam::scope(|s| {
+        let handle = s.spawn(|inner| {
+            let nested = inner.spawn(|_| left.iter().sum::<i32>());
+            nested.join().unwrap() + right.iter().sum::<i32>()
+        });
+        handle.join().unwrap()
+    })
+    .unwrap();
+    println!("Nested spawn sum: {}", total);
+    
+    // The std version (stable since Rust 1.63) looks almost the same
+    let std_total: i32 = thread::scope(|s| {
+        let a = s.spawn(|| left.iter().sum::<i32>());
+        let b = s.spawn(|| right.iter().sum::<i32>());
+        a.join().unwrap() + b.join().unwrap()
+    });
+    println!("std::thread::scope sum: {}", std_total);
+    
+    // Differences worth knowing:
+    // - crossbeam::scope returns a Result; std::thread::scope propagates panics
+    // - crossbeam passes the scope into each thread closure (|s| / |_|)
+    // - std needs no extra dependency, so prefer it in new code
+    println!("Prefer std::thread::scope unless you already depend on crossbeam");
+    println!();
+}
+
+/// Demo 7m: Graceful Shutdown - Stopping workers with a shared flag
+pub fn demo_graceful_shutdown() {
+    println!("=== Demo 7m: Graceful Shutdown ===");
+    
+    use std::sync::atomic::{AtomicBool, Ordering};
+    use std::sync::Arc;
+    use std::thread;
+    use std::time::Duration;
+    
+    let running = Arc::new(AtomicBool::new(true));
+    let mut handles = vec![];
+    
+    for id in 0..3 {
+        let running = Arc::clone(&running);
+        handles.push(thread::spawn(move || {
+            let mut iterations = 0;
+            // Check the flag once per unit of work, never mid-task
+            while running.load(Ordering::Relaxed) {
+                iterations += 1;
+                thread::sleep(Duration::from_millis(50 + id * 20));
+            }
+            println!("  Worker {} saw shutdown, exiting cleanly after {} iterations", id, iterations);
+            iterations
+        }));
+    }
+    
+    thread::sleep(Duration::from_millis(300));
+    println!("Main: requesting shutdown");
+    running.store(false, Ordering::Relaxed);
+    
+    // join() waits for each worker to finish its current iteration
+    let total: u64 = handles.into_iter().map(|h| h.join().unwrap()).sum();
+    println!("Main: all workers stopped ({} iterations total)", total);
+    println!();
+}
+
+/// Demo 7n: Pipeline - Fan-out to workers, fan-in to a collector
+pub fn demo_pipeline() {
+    println!("=== Demo 7n: Pipeline ===");
+    
+    use std::sync::mpsc;
+    use std::sync::{Arc, Mutex};
+    use std::thread;
+    use std::time::Duration;
+    
+    let (job_tx, job_rx) = mpsc::channel::<u64>();
+    let (result_tx, result_rx) = mpsc::channel::<(usize, u64, u64)>();
+    
+    // Stage 1: a generator produces jobs
+    let generator = thread::spawn(move || {
+        for n in 1..=8 {
+            job_tx.send(n).unwrap();
+        }
+        // Dropping job_tx tells the workers there is no more input
+    });
+    
+    // Stage 2: fan-out. mpsc has one receiver, so workers share it behind a Mutex
+    let job_rx = Arc::new(Mutex::new(job_rx));
+    let mut workers = vec![];
+    for id in 0..3 {
+        let job_rx = Arc::clone(&job_rx);
+        let result_tx = result_tx.clone();
+        workers.push(thread::spawn(move || {
+            loop {
+                // The lock guard is dropped at the end of this statement
+                let job = job_rx.lock().unwrap().recv();
+                match job {
+                    Ok(n) => {
+                        thread::sleep(Duration::from_millis(10 * n));
+                        result_tx.send((id, n, n * n)).unwrap();
+                    }
+                    Err(_) => break,
+                }
+            }
+        }));
+    }
+    // Drop the original so the result channel closes once every worker is done
+    drop(result_tx);
+    
+    // Stage 3: fan-in. The collector aggregates whatever arrives first
+    let collector = thread::spawn(move || {
+        let mut results = vec![];
+        for (worker, n, square) in result_rx {
+            println!("  Worker {} computed {}^2 = {}", worker, n, square);
+            results.push((n, square));
+        }
+        results
+    });
+    
+    generator.join().unwrap();
+    for w in workers {
+        w.join().unwrap();
+    }
+    let mut results = collector.join().unwrap();
+    results.sort();
+    
+    let total: u64 = results.iter().map(|(_, sq)| sq).sum();
+    println!("Collected {} results in input order: {:?}", results.len(), results.iter().map(|(_, sq)| sq).collect::<Vec<_>>());
+    println!("Sum of squares: {}", total);
+    println!();
+}
+
+/// Demo 7o: Once - One-time initialization across threads
+pub fn demo_once() {
+    println!("=== Demo 7o: Once ===");
+    
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::{Arc, Barrier, Once};
+    use std::thread;
+    
+    static INIT: Once = Once::new();
+    static INIT_RUNS: AtomicUsize = AtomicUsize::new(0);
+    
+    let threads = 8;
+    // The barrier releases every thread at the same moment to maximize the race
+    let barrier = Arc::new(Barrier::new(threads));
+    let mut handles = vec![];
+    
+    for id in 0..threads {
+        let barrier = Arc::clone(&barrier);
+        handles.push(thread::spawn(move || {
+            barrier.wait();
+            let mut ran_here = false;
+            INIT.call_once(|| {
+                // Other threads block in call_once until this closure returns
+                INIT_RUNS.fetch_add(1, Ordering::SeqCst);
+                ran_here = true;
+            });
+            (id, ran_here)
+        }));
+    }
+    
+    for handle in handles {
+        let (id, ran_here) = handle.join().unwrap();
+        if ran_here {
+            println!("  Thread {} ran the initializer", id);
+        }
+    }
+    
+    println!("{} threads raced for call_once", threads);
+    println!("Initializer body executed {} time(s)", INIT_RUNS.load(Ordering::SeqCst));
+    println!("INIT.is_completed() = {}", INIT.is_completed());
+    // Calling the demo again won't rerun the initializer: Once stays completed for the whole process
+    println!();
+}
+
+/// Demo 7p: Thread Panics - Recovering a panic from join()
+pub fn demo_thread_panic() {
+    println!("=== Demo 7p: Thread Panics ===");
+    
+    use std::any::Any;
+    use std::thread;
+    
+    // A panic payload is Box<dyn Any + Send>; panic!("literal") gives &str, formatted panics give String
+    fn panic_message(payload: &Box<dyn Any + Send>) -> String {
+        if let Some(s) = payload.downcast_ref::<&str>() {
+            s.to_string()
+        } else if let Some(s) = payload.downcast_ref::<String>() {
+            s.clone()
+        } else {
+            "<non-string panic payload>".to_string()
+        }
+    }
+    
+    let literal = thread::spawn(|| {
+        panic!("worker hit an invariant violation");
+    });
+    
+    let formatted = thread::spawn(|| {
+        let config: Vec<u32> = vec![];
+        let index = 3;
+        if index >= config.len() {
+            panic!("config index {} out of range (len {})", index, config.len());
+        }
+    });
+    
+    let healthy = thread::spawn(|| 6 * 7);
+    
+    // The default panic hook still prints each panic to stderr; that output is expected
+    // The panic only unwinds its own thread; join() hands us the payload as Err
+    for (name, handle) in [("literal", literal), ("formatted", formatted)] {
+        match handle.join() {
+            Ok(()) => println!("  {} thread finished normally", name),
+            Err(payload) => println!("  {} thread panicked: {:?}", name, panic_message(&payload)),
+        }
+    }
+    
+    println!("  healthy thread returned {}", healthy.join().unwrap());
+    println!("Main thread is still running after two worker panics");
+    // handle.join().unwrap() on a panicked thread would re-panic here and take main down too
+    println!();
+}
+
+/// Demo 7q: Channel Benchmark - std::sync::mpsc vs crossbeam throughput
+pub fn demo_channel_benchmark() {
+    println!("=== Demo 7q: Channel Benchmark ===");
+    
+    use std::thread;
+    use std::time::{Duration, Instant};
+    
+    // Tune these down if the benchmark starts to dominate the lecture
+    const MESSAGES: u64 = 1_000_000;
+    const WARMUP: u64 = 10_000;
+    
+    fn bench_std(count: u64) -> (Duration, u64) {
+        let (tx, rx) = std::sync::mpsc::channel();
+        let start = Instant::now();
+        let producer = thread::spawn(move || {
+            for i in 0..count {
+                tx.send(i).unwrap();
+            }
+        });
+        let sum: u64 = rx.iter().sum();
+        producer.join().unwrap();
+        (start.elapsed(), sum)
+    }
+    
+    fn bench_crossbeam(count: u64) -> (Duration, u64) {
+        let (tx, rx) = crossbeam::channel::unbounded();
+        let start = Instant::now();
+        let producer = thread::spawn(move || {
+            for i in 0..count {
+                tx.send(i).unwrap();
+            }
+        });
+        let sum: u64 = rx.iter().sum();
+        producer.join().unwrap();
+        (start.elapsed(), sum)
+    }
+    
+    // Warm up thread spawning and allocator paths so the first run isn't penalized
+    bench_std(WARMUP);
+    bench_crossbeam(WARMUP);
+    
+    let (std_time, std_sum) = bench_std(MESSAGES);
+    let (cb_time, cb_sum) = bench_crossbeam(MESSAGES);
+    
+    let throughput = |d: Duration| MESSAGES as f64 / d.as_secs_f64() / 1_000_000.0;
+    println!("Sending {} u64s from one producer to one consumer:", MESSAGES);
+    println!("  std::sync::mpsc:      {:>10.2?}  ({:.1}M msgs/sec)", std_time, throughput(std_time));
+    println!("  crossbeam unbounded:  {:>10.2?}  ({:.1}M msgs/sec)", cb_time, throughput(cb_time));
+    println!("  Both received every message: {}", std_sum == cb_sum && std_sum == MESSAGES * (MESSAGES - 1) / 2);
+    
+    // Since Rust 1.67 std's mpsc is built on crossbeam's design, so raw speed is close;
+    // crossbeam's real advantages are select!, cloneable receivers (MPMC) and bounded(0)
+    println!("Note: numbers vary by machine and build profile; run with --release for real figures");
+    println!();
+}
+
+/// Demo 7r: Send and Sync Markers - Opting in with unsafe impl, opting out with PhantomData
+pub fn demo_send_sync_markers() {
+    println!("=== Demo 7r: Send and Sync Markers ===");
+    
+    use std::marker::PhantomData;
+    use std::thread;
+    
+    // Compile-time checks: these only build if T meets the bound
+    fn assert_send<T: Send>() {}
+    fn assert_sync<T: Sync>() {}
+    
+    // Raw pointers are neither Send nor Sync, and that spreads to any struct holding one
+    struct RawBuffer {
+        _ptr: *mut u8,
+    }
+    // assert_send::<RawBuffer>(); // This would cause a compile error! *mut u8 is not Send
+    let _ = RawBuffer { _ptr: std::ptr::null_mut() };
+    
+    // A wrapper that *uniquely owns* the allocation behind its pointer
+    struct OwnedBuffer {
+        ptr: *mut u8,
+        len: usize,
+    }
+    
+    impl OwnedBuffer {
+        fn new(data: Vec<u8>) -> Self {
+            let len = data.len();
+            let ptr = Box::into_raw(data.into_boxed_slice()) as *mut u8;
+            OwnedBuffer { ptr, len }
+        }
+        
+        fn as_slice(&self) -> &[u8] {
+            // SAFETY: ptr/len came from a Box<[u8]> we own and haven't freed yet
+            unsafe { std::slice::from_raw_parts(self.ptr, self.len) }
+        }
+    }
+    
+    impl Drop for OwnedBuffer {
+        fn drop(&mut self) {
+            // SAFETY: rebuilds the Box exactly once, from the parts into_raw gave us
+            unsafe { drop(Box::from_raw(std::ptr::slice_from_raw_parts_mut(self.ptr, self.len))) }
+        }
+    }
+    
+    // SAFETY: OwnedBuffer is the only owner of its allocation (no aliases, no Rc), so
+    // moving it to another thread moves that exclusive ownership along with it.
+    // This is the same reasoning that makes Box<[u8]> and Vec<u8> Send.
+    unsafe impl Send for OwnedBuffer {}
+    assert_send::<OwnedBuffer>();
+    // We deliberately don't implement Sync; nothing here needs shared cross-thread access
+    
+    let buffer = OwnedBuffer::new(b"moved across threads".to_vec());
+    let handle = thread::spawn(move || {
+        let text = String::from_utf8_lossy(buffer.as_slice()).into_owned();
+        (text, buffer.len)
+    });
+    let (text, len) = handle.join().unwrap();
+    println!("OwnedBuffer (unsafe impl Send) read on another thread: {:?} ({} bytes)", text, len);
+    
+    // Opting out: PhantomData<*const ()> makes a type !Send and !Sync at zero cost,
+    // e.g. for handles that must stay on the thread that created them
+    struct MainThreadOnly {
+        id: u32,
+        _not_send: PhantomData<*const ()>,
+    }
+    
+    let gui_handle = MainThreadOnly { id: 7, _not_send: PhantomData };
+    // thread::spawn(move || drop(gui_handle)); // This would cause a compile error! MainThreadOnly is not Send
+    // assert_sync::<MainThreadOnly>();           // Nor is it Sync
+    println!("MainThreadOnly handle {} stays on the thread that made it", gui_handle.id);
+    
+    // Ordinary types get both markers automatically
+    assert_send::<Vec<String>>();
+    assert_sync::<Vec<String>>();
+    println!("Vec<String> is Send + Sync automatically; no unsafe needed");
+    println!();
+}
+
+/// Demo 7s: Cancellation Token - A reusable shutdown signal shared by workers
+pub fn demo_cancellation_token() {
+    println!("=== Demo 7s: Cancellation Token ===");
+    
+    use std::sync::atomic::{AtomicBool, Ordering};
+    use std::sync::Arc;
+    use std::thread;
+    use std::time::Duration;
+    
+    // Wraps the Arc<AtomicBool> from Demo 7m behind a small, intention-revealing API
+    #[derive(Clone, Default)]
+    struct CancelToken(Arc<AtomicBool>);
+    
+    impl CancelToken {
+        fn new() -> Self {
+            Self::default()
+        }
+        
+        fn cancel(&self) {
+            self.0.store(true, Ordering::Release);
+        }
+        
+        fn is_cancelled(&self) -> bool {
+            self.0.load(Ordering::Acquire)
+        }
+    }
+    
+    // Workers only see the token, not how cancellation is implemented
+    fn worker(id: usize, token: CancelToken, units: usize, unit_time: Duration) -> usize {
+        let mut done = 0;
+        for _ in 0..units {
+            if token.is_cancelled() {
+                break;
+            }
+            thread::sleep(unit_time); // One unit of work
+            done += 1;
+        }
+        println!("  Worker {} stopping: {}/{} units done{}", id, done, units,
+                 if done < units { " (cancelled)" } else { "" });
+        done
+    }
+    
+    let token = CancelToken::new();
+    let speeds = [20, 35, 60, 5];
+    let handles: Vec<_> = speeds
+        .iter()
+        .enumerate()
+        .map(|(id, &ms)| {
+            let token = token.clone();
+            thread::spawn(move || worker(id, token, 10, Duration::from_millis(ms)))
+        })
+        .collect();
+    
+    thread::sleep(Duration::from_millis(150));
+    println!("Main: cancelling after 150ms");
+    token.cancel();
+    
+    let completed: Vec<usize> = handles.into_iter().map(|h| h.join().unwrap()).collect();
+    println!("Units completed per worker: {:?}", completed);
+    println!("Token cancelled: {}", token.is_cancelled());
+    println!();
+}
+
+/// Header printed by `run_all_demos`
+pub const BANNER: &str = concat!(crab!(), " RUST LECTURE - SECTION 7: FEARLESS CONCURRENCY ", crab!());
+
+/// Concepts listed in the recap printed after `run_all_demos`
+pub const RECAP: &[&str] = &[
+    "thread::spawn runs work; join waits for the result",
+    "Channels move data; Arc<Mutex<T>> shares it",
+    "Send and Sync are checked at compile time",
+    "Rust prevents data races, not deadlocks",
+    "Atomics and Once coordinate shutdown and setup",
+    "A panicking thread returns Err from join",
+];
+
 /// Run all demos in sequence
 pub fn run_all_demos() {
-    println!("🦀 RUST LECTURE - SECTION 7: FEARLESS CONCURRENCY 🦀");
+    println!("{}", BANNER);
     println!("======================================================");
     println!();
     
@@ -508,9 +1219,22 @@ pub fn run_all_demos() {
     demo_advanced_concurrency();
     demo_async_basics();
     demo_thread_safety();
-    
-    println!("✅ Section 7 complete!");
-    println!("💡 Key takeaway: Rust's type system prevents data races and ensures memory safety in concurrent code!");
+    demo_barrier();
+    demo_real_async();
+    demo_deadlock_avoidance();
+    demo_worker_pool();
+    demo_recv_timeout();
+    demo_crossbeam_scope();
+    demo_graceful_shutdown();
+    demo_pipeline();
+    demo_once();
+    demo_thread_panic();
+    demo_channel_benchmark();
+    demo_send_sync_markers();
+    demo_cancellation_token();
+    
+    println!(concat!(check!(), " Section 7 complete!"));
+    crate::lecture_utils::print_recap("Section 7: Concurrency", RECAP);
 }
 
 /// Get list of available demos for enhanced navigation
@@ -522,5 +1246,18 @@ pub fn get_demo_list() -> Vec<&'static str> {
         "advanced",
         "async",
         "safety",
+        "barrier",
+        "real_async",
+        "deadlock",
+        "worker_pool",
+        "recv_timeout",
+        "crossbeam_scope",
+        "shutdown",
+        "pipeline",
+        "once",
+        "thread_panic",
+        "channel_bench",
+        "send_sync",
+        "cancellation",
     ]
 }
\ No newline at end of file
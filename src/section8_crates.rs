@@ -67,6 +67,120 @@ pub fn demo_1_serde_json() {
         println!("First user name: {}", first_user);
     }
     
+    // Building and mutating dynamic JSON without a struct
+    use serde_json::json;
+    
+    let mut doc = json!({
+        "users": [
+            { "name": "Carol", "active": false },
+            { "name": "Dan", "active": true }
+        ],
+        "meta": { "version": 1 }
+    });
+    doc["users"][0]["active"] = json!(true);
+    doc["meta"]["version"] = json!(2);
+    if let Some(users) = doc["users"].as_array_mut() {
+        users.push(json!({ "name": "Erin", "active": false }));
+    }
+    println!("Mutated JSON:\n{}", serde_json::to_string_pretty(&doc).unwrap());
+    
+    // Indexing a missing key yields Null; .get() makes the absence explicit
+    match doc.get("pagination") {
+        Some(p) => println!("Pagination: {}", p),
+        None => println!("No \"pagination\" key (doc[\"pagination\"] would be {})", doc["pagination"]),
+    }
+    
+    // Customizing the wire format with serde attributes
+    #[derive(Serialize, Deserialize, Debug)]
+    #[serde(tag = "type")]  // Internally tagged: {"type": "Premium", ...}
+    enum Plan {
+        Free,
+        Premium { seats: u32 },
+    }
+    
+    #[derive(Serialize, Deserialize, Debug)]
+    struct Account {
+        #[serde(rename = "userName")]  // Rust field name differs from JSON key
+        user_name: String,
+        
+        #[serde(default)]  // Missing in JSON -> Vec::default()
+        roles: Vec<String>,
+        
+        #[serde(skip_serializing_if = "Option::is_none")]  // Omit the key entirely when None
+        nickname: Option<String>,
+        
+        plan: Plan,
+    }
+    
+    let accounts = [
+        Account {
+            user_name: "alice".to_string(),
+            roles: vec!["admin".to_string()],
+            nickname: Some("Al".to_string()),
+            plan: Plan::Premium { seats: 5 },
+        },
+        Account {
+            user_name: "bob".to_string(),
+            roles: vec![],
+            nickname: None,
+            plan: Plan::Free,
+        },
+    ];
+    
+    println!("\nCustom serde attributes:");
+    for account in &accounts {
+        println!("  {}", serde_json::to_string(account).unwrap());
+    }
+    
+    // "roles" and "nickname" are absent; #[serde(default)] and Option fill them in
+    let incoming = r#"{"userName": "carol", "plan": {"type": "Premium", "seats": 2}}"#;
+    let parsed: Account = serde_json::from_str(incoming).unwrap();
+    println!("  Parsed from {}:", incoming);
+    println!("    {:?}", parsed);
+    
+    // Untagged enums: no "type" field, serde tries each variant in order
+    // and picks the first one whose shape fits
+    #[derive(Deserialize, Debug)]
+    #[serde(untagged)]
+    enum Response {
+        Success { data: String },
+        Error { code: u32, message: String },
+    }
+    
+    let payloads = [
+        r#"{"data": "42 widgets shipped"}"#,
+        r#"{"code": 404, "message": "Not Found"}"#,
+        r#"{"status": "weird"}"#,
+    ];
+    
+    println!("\nUntagged enum deserialization:");
+    for payload in payloads {
+        match serde_json::from_str::<Response>(payload) {
+            Ok(Response::Success { data }) => println!("  {} -> Success arm: {}", payload, data),
+            Ok(Response::Error { code, message }) => println!("  {} -> Error arm: {} {}", payload, code, message),
+            // The error can't say which field was wrong, only that nothing matched
+            Err(e) => println!("  {} -> no variant matched: {}", payload, e),
+        }
+    }
+    
+    // Locating bad input: serde_json errors know the line, column and kind of failure
+    println!("\nDeserialization error locations:");
+    let bad_payloads = [
+        ("syntax error", "{\n  \"name\": \"Dana\",\n  \"age\": 41,\n  \"email\": \"dana@example.com\"\n  \"active\": true\n}"),
+        ("type mismatch", "{\n  \"name\": \"Dana\",\n  \"age\": \"forty-one\",\n  \"email\": \"dana@example.com\",\n  \"active\": true\n}"),
+        ("truncated", "{\n  \"name\": \"Dana\",\n  \"age\": 41"),
+    ];
+    for (label, payload) in bad_payloads {
+        if let Err(err) = serde_json::from_str::<User>(payload) {
+            // classify() tells syntax problems apart from valid JSON with the wrong shape
+            println!("  {}: {:?} at line {}, column {}", label, err.classify(), err.line(), err.column());
+            println!("    {}", err);
+            if let Some(bad_line) = payload.lines().nth(err.line().saturating_sub(1)) {
+                println!("    > {}", bad_line);
+            }
+        }
+    }
+    
     println!();
 }
 
@@ -92,6 +206,8 @@ pub fn demo_2_rand() {
     let colors = ["red", "green", "blue", "yellow", "purple"];
     println!("Random color: {}", colors.choose(&mut rng).unwrap());
     
+    crate::lecture_utils::step("Next: shuffling, strings and distributions");
+    
     // Shuffle a vector
     let mut numbers: Vec<i32> = (1..=10).collect();
     numbers.shuffle(&mut rng);
@@ -110,6 +226,20 @@ pub fn demo_2_rand() {
     let samples: Vec<i32> = (0..5).map(|_| uniform.sample(&mut rng)).collect();
     println!("Uniform samples [10,20): {:?}", samples);
     
+    crate::lecture_utils::step("Next: seeded RNGs");
+    
+    // Seeded RNG - the same seed always produces the same sequence
+    println!("\nSeeded RNG (reproducible):");
+    let seed = 42;
+    for run in 1..=2 {
+        let mut seeded = StdRng::seed_from_u64(seed);
+        let mut deck: Vec<i32> = (1..=10).collect();
+        deck.shuffle(&mut seeded);
+        let rolls: Vec<u32> = (0..5).map(|_| seeded.gen_range(1..=6)).collect();
+        println!("  Run {} (seed {}): shuffle {:?}, rolls {:?}", run, seed, deck, rolls);
+    }
+    println!("  Note: seed your RNG for tests and repeatable demos, use thread_rng() otherwise");
+    
     println!();
 }
 
@@ -159,6 +289,95 @@ pub fn demo_3_clap() {
                 )
         );
     
+    // The same CLI using the derive API: the struct *is* the argument spec
+    use clap::{Parser, Subcommand, ValueEnum};
+    use std::path::PathBuf;
+    
+    #[derive(Parser, Debug)]
+    #[command(name = "myapp", version = "1.0", about = "Demonstrates clap argument parsing")]
+    struct Cli {
+        /// Sets the input file to use
+        #[arg(short, long, value_name = "FILE")]
+        input: Option<PathBuf>,
+        
+        /// Enable verbose output
+        #[arg(short, long)]
+        verbose: bool,
+        
+        /// Number of iterations
+        #[arg(short, long, value_name = "NUMBER")]
+        count: Option<u32>,
+        
+        #[command(subcommand)]
+        command: Option<Commands>,
+    }
+    
+    #[derive(Subcommand, Debug)]
+    enum Commands {
+        /// Process data
+        Process {
+            /// Output format
+            #[arg(long, value_enum)]
+            format: Option<Format>,
+        },
+    }
+    
+    #[derive(ValueEnum, Clone, Copy, Debug)]
+    enum Format {
+        Json,
+        Xml,
+        Csv,
+    }
+    
+    // Describe the parsed arguments the same way for both APIs so we can compare them
+    #[allow(clippy::collapsible_match, clippy::single_match)]
+    fn summarize_builder(matches: &ArgMatches) -> Vec<String> {
+        let mut summary = Vec::new();
+        if matches.get_flag("verbose") {
+            summary.push("Verbose mode enabled".to_string());
+        }
+        if let Some(input) = matches.get_one::<String>("input") {
+            summary.push(format!("Input file: {}", input));
+        }
+        if let Some(count) = matches.get_one::<u32>("count") {
+            summary.push(format!("Count: {}", count));
+        }
+        if let Some(subcommand) = matches.subcommand() {
+            match subcommand {
+                ("process", sub_matches) => {
+                    summary.push("Processing data".to_string());
+                    if let Some(format) = sub_matches.get_one::<String>("format") {
+                        summary.push(format!("Output format: {}", format));
+                    }
+                }
+                _ => {}
+            }
+        }
+        summary
+    }
+    
+    fn summarize_derive(cli: &Cli) -> Vec<String> {
+        let mut summary = Vec::new();
+        if cli.verbose {
+            summary.push("Verbose mode enabled".to_string());
+        }
+        if let Some(input) = &cli.input {
+            summary.push(format!("Input file: {}", input.display()));
+        }
+        if let Some(count) = cli.count {
+            summary.push(format!("Count: {}", count));
+        }
+        if let Some(Commands::Process { format }) = &cli.command {
+            summary.push("Processing data".to_string());
+            if let Some(format) = format {
+                // ValueEnum gives us the same lowercase names the builder accepted
+                let name = format.to_possible_value().unwrap();
+                summary.push(format!("Output format: {}", name.get_name()));
+            }
+        }
+        summary
+    }
+    
     // Simulate different command line arguments
     let test_args = vec![
         vec!["myapp", "--verbose", "--count", "5", "--input", "data.txt"],
@@ -175,33 +394,22 @@ pub fn demo_3_clap() {
             continue;
         }
         
-        match app.clone().try_get_matches_from(args) {
-            Ok(matches) => {
-                if matches.get_flag("verbose") {
-                    println!("  Verbose mode enabled");
-                }
-                
-                if let Some(input) = matches.get_one::<String>("input") {
-                    println!("  Input file: {}", input);
-                }
-                
-                if let Some(count) = matches.get_one::<u32>("count") {
-                    println!("  Count: {}", count);
+        let builder_result = app.clone().try_get_matches_from(&args);
+        let derive_result = Cli::try_parse_from(&args);
+        
+        match (builder_result, derive_result) {
+            (Ok(matches), Ok(cli)) => {
+                let builder_summary = summarize_builder(&matches);
+                println!("  Builder API:");
+                for line in &builder_summary {
+                    println!("    {}", line);
                 }
                 
-                if let Some(subcommand) = matches.subcommand() {
-                    match subcommand {
-                        ("process", sub_matches) => {
-                            println!("  Processing data");
-                            if let Some(format) = sub_matches.get_one::<String>("format") {
-                                println!("  Output format: {}", format);
-                            }
-                        }
-                        _ => {}
-                    }
-                }
+                println!("  Derive API: {:?}", cli);
+                let same = builder_summary == summarize_derive(&cli);
+                println!("  Both APIs agree: {}", if same { "✓ yes" } else { "✗ no" });
             }
-            Err(e) => {
+            (Err(e), _) | (_, Err(e)) => {
                 println!("  Error: {}", e);
             }
         }
@@ -213,7 +421,9 @@ pub fn demo_3_clap() {
 pub fn demo_4_tokio() {
     println!("=== Demo 4: Tokio ===");
     
+    use std::sync::Arc;
     use tokio::time::{sleep, timeout, Instant};
+    use tokio::sync::Mutex;
     use tokio::task;
     
     // Create a simple async runtime for demonstration
@@ -266,12 +476,91 @@ pub fn demo_4_tokio() {
         
         let result = handle.await.unwrap();
         println!("{}", result);
+        
+        // select! - race several futures, run the first one to finish and
+        // drop the rest (the async cousin of crossbeam's select! in demo 10)
+        println!("\nRacing with tokio::select!:");
+        let mut ticker = tokio::time::interval(Duration::from_millis(40));
+        let fast = sleep(Duration::from_millis(60));
+        let slow = sleep(Duration::from_millis(150));
+        tokio::pin!(fast, slow);
+        
+        let start = Instant::now();
+        let mut fast_done = false;
+        loop {
+            tokio::select! {
+                _ = ticker.tick() => {
+                    println!("  [{:>3}ms] tick", start.elapsed().as_millis());
+                }
+                _ = &mut fast, if !fast_done => {
+                    println!("  [{:>3}ms] fast branch won this round", start.elapsed().as_millis());
+                    fast_done = true;
+                }
+                _ = &mut slow => {
+                    println!("  [{:>3}ms] slow branch finished, leaving the loop", start.elapsed().as_millis());
+                    break;
+                }
+            }
+        }
+        
+        // Async channels: the tokio counterpart of std::sync::mpsc from section 7
+        println!("\nAsync mpsc channel:");
+        let (tx, mut rx) = tokio::sync::mpsc::channel::<u32>(32);
+        let producer = task::spawn(async move {
+            for reading in [12, 17, 23, 31, 42] {
+                // send().await waits (without blocking the thread) if the buffer is full
+                tx.send(reading).await.unwrap();
+                sleep(Duration::from_millis(20)).await;
+            }
+            // Dropping tx closes the channel, which ends the loop below
+        });
+        
+        while let Some(reading) = rx.recv().await {
+            println!("  received {}", reading);
+        }
+        producer.await.unwrap();
+        
+        // Bounded capacity is the backpressure: a full channel refuses more work
+        let (tx, mut rx) = tokio::sync::mpsc::channel::<&str>(1);
+        tx.try_send("first").unwrap();
+        match tx.try_send("second") {
+            Ok(()) => println!("  second message queued"),
+            Err(e) => println!("  capacity 1, try_send(\"second\") -> {}", e),
+        }
+        println!("  drained {:?}, now there's room again: {:?}", rx.recv().await, tx.try_send("second").is_ok());
+        
+        // Async Mutex: the guard may be held across .await. A std::sync::MutexGuard held
+        // across an .await can deadlock (another task blocks the thread in lock() while the
+        // holder never gets polled again), and since the guard isn't Send, task::spawn
+        // rejects such a future outright
+        println!("\nAsync Mutex shared across tasks:");
+        let balance = Arc::new(Mutex::new(0u32));
+        let mut depositors = Vec::new();
+        for id in 0..5 {
+            let balance = Arc::clone(&balance);
+            depositors.push(task::spawn(async move {
+                for _ in 0..10 {
+                    let mut guard = balance.lock().await;
+                    let current = *guard;
+                    // Simulate an async call (e.g. an audit log write) while holding the lock
+                    sleep(Duration::from_millis(1)).await;
+                    *guard = current + 1;
+                }
+                id
+            }));
+        }
+        for depositor in depositors {
+            depositor.await.unwrap();
+        }
+        println!("  5 tasks x 10 deposits, final balance: {}", *balance.lock().await);
+        // With no .await while locked, std::sync::Mutex is fine (and faster) in async code
     });
     
     println!();
 }
 
 /// Demo 5: Reqwest - HTTP Client
+#[allow(clippy::collapsible_if)]
 pub fn demo_5_reqwest() {
     println!("=== Demo 5: Reqwest ===");
     
@@ -345,7 +634,7 @@ pub fn demo_5_reqwest() {
 pub fn demo_6_regex() {
     println!("=== Demo 6: Regex ===");
     
-    use regex::Regex;
+    use regex::{Captures, Regex};
     
     // Email validation
     let email_regex = Regex::new(r"^[a-zA-Z0-9._%+-]+@[a-zA-Z0-9.-]+\.[a-zA-Z]{2,}$").unwrap();
@@ -397,6 +686,50 @@ pub fn demo_6_regex() {
     println!("  Original: {}", text);
     println!("  Replaced: {}", replaced);
     
+    // Replace with a closure - compute each replacement from its captures
+    let contact = "Call me at 555-123-4567 or 800-555-0199";
+    let masked = phone_regex.replace_all(contact, |caps: &Captures| {
+        format!("***-***-{}", &caps["number"])
+    });
+    println!("\nReplace with closure (masking):");
+    println!("  Before: {}", contact);
+    println!("  After:  {}", masked);
+    
+    let shouted = animal_regex.replace_all(text, |caps: &Captures| caps[1].to_uppercase());
+    println!("\nReplace with closure (uppercasing matches):");
+    println!("  Before: {}", text);
+    println!("  After:  {}", shouted);
+    
+    // Compile once, reuse everywhere: a LazyLock static builds the Regex on first use
+    // (see Demo 15 for LazyLock/OnceLock in general)
+    use std::sync::LazyLock;
+    use std::time::Instant;
+    
+    static EMAIL: LazyLock<Regex> = LazyLock::new(|| {
+        Regex::new(r"^[a-zA-Z0-9._%+-]+@[a-zA-Z0-9.-]+\.[a-zA-Z]{2,}$").unwrap()
+    });
+    
+    let inputs: Vec<String> = (0..2_000)
+        .map(|i| if i % 3 == 0 { format!("user{}@example.com", i) } else { format!("user{}-at-example", i) })
+        .collect();
+    
+    // The common mistake: Regex::new inside the loop recompiles on every iteration
+    let start = Instant::now();
+    let slow_count = inputs
+        .iter()
+        .filter(|s| Regex::new(r"^[a-zA-Z0-9._%+-]+@[a-zA-Z0-9.-]+\.[a-zA-Z]{2,}$").unwrap().is_match(s))
+        .count();
+    let recompile_time = start.elapsed();
+    
+    let start = Instant::now();
+    let fast_count = inputs.iter().filter(|s| EMAIL.is_match(s)).count();
+    let static_time = start.elapsed();
+    
+    println!("\nCompile once vs. recompile ({} inputs):", inputs.len());
+    println!("  Regex::new in the loop: {} matches, took {:?}", slow_count, recompile_time);
+    println!("  static LazyLock<Regex>: {} matches, took {:?}", fast_count, static_time);
+    println!("  Speedup: {:.0}x", recompile_time.as_nanos() as f64 / static_time.as_nanos().max(1) as f64);
+    
     println!();
 }
 
@@ -436,6 +769,61 @@ pub fn demo_7_chrono() {
     println!("  Days since birthday: {}", age.num_days());
     println!("  Years (approx): {:.1}", age.num_days() as f64 / 365.25);
     
+    // Breaking a Duration into human-sized units (years here are 365 days)
+    fn breakdown(d: Duration) -> String {
+        let d = d.abs();
+        let years = d.num_days() / 365;
+        let days = d.num_days() % 365;
+        let hours = d.num_hours() % 24;
+        let minutes = d.num_minutes() % 60;
+        
+        let parts: Vec<String> = [(years, "year"), (days, "day"), (hours, "hour"), (minutes, "minute")]
+            .iter()
+            .filter(|(n, _)| *n > 0)
+            .map(|(n, unit)| format!("{} {}{}", n, unit, if *n == 1 { "" } else { "s" }))
+            .collect();
+        if parts.is_empty() { "0 minutes".to_string() } else { parts.join(", ") }
+    }
+    
+    // "3 hours ago" / "in 2 days": the sign says past or future, the largest unit wins
+    fn relative(then: DateTime<Local>, now: DateTime<Local>) -> String {
+        let diff = then.signed_duration_since(now);
+        let d = diff.abs();
+        let (n, unit) = if d.num_days() >= 365 {
+            (d.num_days() / 365, "year")
+        } else if d.num_days() >= 1 {
+            (d.num_days(), "day")
+        } else if d.num_hours() >= 1 {
+            (d.num_hours(), "hour")
+        } else if d.num_minutes() >= 1 {
+            (d.num_minutes(), "minute")
+        } else {
+            return "just now".to_string();
+        };
+        let plural = if n == 1 { "" } else { "s" };
+        if diff < Duration::zero() {
+            format!("{} {}{} ago", n, unit, plural)
+        } else {
+            format!("in {} {}{}", n, unit, plural)
+        }
+    }
+    
+    println!("  Age breakdown: {}", breakdown(age));
+    
+    println!("\nHumanized durations:");
+    let examples = [
+        Duration::seconds(20),
+        -Duration::minutes(45),
+        -Duration::hours(3),
+        Duration::days(2) + Duration::hours(5),
+        -Duration::days(400) - Duration::minutes(90),
+        Duration::weeks(3),
+    ];
+    for offset in examples {
+        let then = now_local + offset;
+        println!("  {:<16} ({})", relative(then, now_local), breakdown(offset));
+    }
+    
     // Working with different time zones
     let utc_time = Utc::now();
     let tokyo_offset = chrono::FixedOffset::east_opt(9 * 3600).unwrap(); // UTC+9
@@ -451,6 +839,29 @@ pub fn demo_7_chrono() {
     println!("  RFC 2822: {}", now_local.to_rfc2822());
     println!("  Custom: {}", now_local.format("%A, %B %d, %Y at %I:%M %p"));
     
+    // Timezone parsing - offset-aware timestamps keep their UTC offset
+    println!("\nTimezone parsing:");
+    let tokyo_str = "2025-06-04T14:30:15+09:00";
+    let new_york_str = "2025-06-04T08:15:00-04:00";
+    
+    let tokyo_meeting: DateTime<chrono::FixedOffset> = DateTime::parse_from_rfc3339(tokyo_str).unwrap();
+    let new_york_meeting = DateTime::parse_from_rfc3339(new_york_str).unwrap();
+    
+    println!("  Parsed: {} (offset {})", tokyo_meeting, tokyo_meeting.offset());
+    println!("  As UTC:   {}", tokyo_meeting.with_timezone(&Utc));
+    println!("  As Local: {}", tokyo_meeting.with_timezone(&Local));
+    
+    // Subtraction compares instants, so differing offsets are handled for us
+    let gap = new_york_meeting.signed_duration_since(tokyo_meeting);
+    println!("  {} -> {}", tokyo_str, new_york_str);
+    println!("  Time between them: {} hours {} minutes", gap.num_hours(), gap.num_minutes() % 60);
+    
+    // Without an offset, RFC 3339 parsing fails rather than guessing a zone
+    match DateTime::parse_from_rfc3339("2025-06-04T14:30:15") {
+        Ok(dt) => println!("  Unexpected success: {}", dt),
+        Err(e) => println!("  Missing offset -> Error: {}", e),
+    }
+    
     println!();
 }
 
@@ -560,6 +971,59 @@ port=8080
         }
     }
     
+    // Downcasting - recover the concrete error type behind an anyhow::Error
+    #[derive(thiserror::Error, Debug)]
+    enum SettingsError {
+        #[error("missing required key '{0}'")]
+        MissingKey(String),
+        
+        #[error("port {0} is out of range")]
+        InvalidPort(u32),
+    }
+    
+    fn load_port(settings: &HashMap<String, String>) -> Result<u16> {
+        let raw = settings
+            .get("port")
+            .ok_or_else(|| SettingsError::MissingKey("port".to_string()))
+            .context("Loading server settings")?;
+        let port: u32 = raw.parse().context("Port is not a number")?;
+        let port = u16::try_from(port)
+            .map_err(|_| SettingsError::InvalidPort(port))
+            .context("Loading server settings")?;
+        Ok(port)
+    }
+    
+    println!("\nDowncasting to concrete errors:");
+    let scenarios = [
+        HashMap::new(),
+        HashMap::from([("port".to_string(), "99999".to_string())]),
+        HashMap::from([("port".to_string(), "8080".to_string())]),
+    ];
+    
+    for settings in &scenarios {
+        match load_port(settings) {
+            Ok(port) => println!("  Loaded port {}", port),
+            Err(e) => {
+                // downcast_ref looks through .context() to the original error
+                match e.downcast_ref::<SettingsError>() {
+                    Some(SettingsError::MissingKey(key)) => {
+                        println!("  Downcast succeeded: missing '{}', falling back to default", key)
+                    }
+                    Some(SettingsError::InvalidPort(port)) => {
+                        println!("  Downcast succeeded: rejecting port {}", port)
+                    }
+                    None => println!("  Downcast failed: {}", e),
+                }
+            }
+        }
+    }
+    
+    // is::<T>() answers "what kind of error is this?" without borrowing it out
+    if let Err(e) = read_config_file("nonexistent.conf") {
+        println!("  Missing file is io::Error: {}", e.is::<std::io::Error>());
+        println!("  Missing file is SettingsError: {}", e.is::<SettingsError>());
+    }
+    
     println!();
 }
 
@@ -684,6 +1148,46 @@ pub fn demo_9_thiserror() {
         }
     }
     
+    // Typed errors at the boundary, anyhow at the top:
+    // io::Error --#[from]--> DataProcessingError --?--> anyhow::Error + context
+    use anyhow::Context;
+    
+    fn read_user_file(path: &std::path::Path) -> Result<String, DataProcessingError> {
+        let contents = std::fs::read_to_string(path)?; // io::Error -> DataProcessingError::Io
+        let value: serde_json::Value = serde_json::from_str(&contents)?; // -> DataProcessingError::Json
+        Ok(value["name"].as_str().unwrap_or("unknown").to_string())
+    }
+    
+    fn load_user(path: &std::path::Path) -> anyhow::Result<String> {
+        let name = read_user_file(path)
+            .with_context(|| format!("failed to load user from {}", path.display()))?;
+        Ok(name)
+    }
+    
+    let dir = tempfile::tempdir().unwrap();
+    let good = dir.path().join("user.json");
+    let bad = dir.path().join("broken.json");
+    std::fs::write(&good, r#"{"name": "Erin"}"#).unwrap();
+    std::fs::write(&bad, "{ not json").unwrap();
+    let missing = dir.path().join("missing.json");
+    
+    println!("\nErrors flowing from thiserror into anyhow:");
+    for path in [&good, &bad, &missing] {
+        match load_user(path) {
+            Ok(name) => println!("  ✓ Loaded user {}", name),
+            Err(e) => {
+                println!("  ✗ {}", e);
+                for cause in e.chain().skip(1) {
+                    println!("    Caused by: {}", cause);
+                }
+                // The typed error is still inside and can be recovered
+                if let Some(DataProcessingError::Io(io)) = e.downcast_ref::<DataProcessingError>() {
+                    println!("    (downcast to DataProcessingError::Io, kind = {:?})", io.kind());
+                }
+            }
+        }
+    }
+    
     println!();
 }
 
@@ -793,20 +1297,40 @@ pub fn demo_11_rayon() {
     // Parallel iteration
     let numbers: Vec<i32> = (1..=1000).collect();
     
+    // Per-element work must outweigh the cost of splitting and scheduling;
+    // squaring 1000 numbers is too cheap and parallel comes out *slower*
+    fn expensive(x: u64) -> u64 {
+        let mut hash = x;
+        for _ in 0..16 {
+            hash ^= hash << 13;
+            hash ^= hash >> 7;
+            hash ^= hash << 17;
+        }
+        hash % 1000
+    }
+    
+    let workload: Vec<u64> = (1..=5_000_000).collect();
+    
+    // Warm-up so thread-pool start-up isn't counted in the parallel measurement
+    let _: u64 = workload[..1000].par_iter().map(|&x| expensive(x)).sum();
+    
     // Sequential processing
     let start = Instant::now();
-    let sequential_sum: i32 = numbers.iter().map(|&x| x * x).sum();
+    let sequential_sum: u64 = workload.iter().map(|&x| expensive(x)).sum();
     let sequential_time = start.elapsed();
     
     // Parallel processing
     let start = Instant::now();
-    let parallel_sum: i32 = numbers.par_iter().map(|&x| x * x).sum();
+    let parallel_sum: u64 = workload.par_iter().map(|&x| expensive(x)).sum();
     let parallel_time = start.elapsed();
     
-    println!("Sum of squares (1-1000):");
+    println!("Hash-mixing 5,000,000 numbers on {} threads:", rayon::current_num_threads());
     println!("  Sequential: {} (took {:?})", sequential_sum, sequential_time);
     println!("  Parallel:   {} (took {:?})", parallel_sum, parallel_time);
     println!("  Speedup: {:.2}x", sequential_time.as_nanos() as f64 / parallel_time.as_nanos() as f64);
+    if rayon::current_num_threads() == 1 {
+        println!("  (Only one CPU available, so no speedup is possible here)");
+    }
     
     // Parallel filtering and mapping
     let words = vec![
@@ -849,6 +1373,104 @@ pub fn demo_11_rayon() {
     println!("\nParallel find:");
     println!("  Looking for {}: {:?}", target, found);
     
+    // Divide and conquer with rayon::join: both halves *may* run in parallel,
+    // and small slices fall back to sequential code to avoid splitting overhead
+    fn merge(left: &[u64], right: &[u64], out: &mut [u64]) {
+        let (mut i, mut j) = (0, 0);
+        for slot in out.iter_mut() {
+            if j >= right.len() || (i < left.len() && left[i] <= right[j]) {
+                *slot = left[i];
+                i += 1;
+            } else {
+                *slot = right[j];
+                j += 1;
+            }
+        }
+    }
+    
+    fn merge_sort_seq(v: &mut [u64]) {
+        if v.len() <= 1 {
+            return;
+        }
+        let mid = v.len() / 2;
+        merge_sort_seq(&mut v[..mid]);
+        merge_sort_seq(&mut v[mid..]);
+        let mut buffer = v.to_vec();
+        merge(&v[..mid], &v[mid..], &mut buffer);
+        v.copy_from_slice(&buffer);
+    }
+    
+    fn merge_sort_par(v: &mut [u64]) {
+        if v.len() <= 4096 {
+            return merge_sort_seq(v);
+        }
+        let mid = v.len() / 2;
+        let (left, right) = v.split_at_mut(mid);
+        rayon::join(|| merge_sort_par(left), || merge_sort_par(right));
+        let mut buffer = v.to_vec();
+        merge(&v[..mid], &v[mid..], &mut buffer);
+        v.copy_from_slice(&buffer);
+    }
+    
+    let unsorted: Vec<u64> = workload[..200_000].iter().map(|&x| expensive(x) * 1000 + x % 1000).collect();
+    
+    let mut seq_data = unsorted.clone();
+    let start = Instant::now();
+    merge_sort_seq(&mut seq_data);
+    let seq_sort_time = start.elapsed();
+    
+    let mut par_data = unsorted.clone();
+    let start = Instant::now();
+    merge_sort_par(&mut par_data);
+    let par_sort_time = start.elapsed();
+    
+    println!("\nRecursive merge sort of 200,000 numbers (rayon::join):");
+    println!("  Sequential recursion: took {:?}", seq_sort_time);
+    println!("  rayon::join halves:   took {:?}", par_sort_time);
+    println!("  Same result: {}", seq_data == par_data);
+    println!("  Sorted: {}", par_data.windows(2).all(|w| w[0] <= w[1]));
+    println!("  Smallest five: {:?}", &par_data[..5]);
+    
+    // par_bridge: parallelize a plain sequential Iterator that rayon can't
+    // split by index (here, lines of text filtered on the fly)
+    use rayon::iter::ParallelBridge;
+    
+    let log_text = (1..=2000)
+        .map(|i| if i % 7 == 0 { format!("ERROR request {} failed", i) } else { format!("INFO request {} ok", i) })
+        .collect::<Vec<_>>()
+        .join("\n");
+    
+    let error_ids: Vec<u64> = log_text
+        .lines()
+        .filter(|line| line.starts_with("ERROR"))
+        .par_bridge()
+        .map(|line| line.split_whitespace().nth(2).unwrap().parse::<u64>().unwrap())
+        .collect();
+    let error_sum: u64 = error_ids.iter().sum();
+    
+    println!("\npar_bridge over str::lines():");
+    println!("  Found {} error lines, id sum {}", error_ids.len(), error_sum);
+    println!("  First ids as collected: {:?}", &error_ids[..5]);
+    println!("  (par_bridge doesn't preserve order; sort afterwards if it matters)");
+    
+    // A dedicated pool caps parallelism instead of using one thread per core
+    let pool = rayon::ThreadPoolBuilder::new()
+        .num_threads(2)
+        .build()
+        .unwrap();
+    
+    // install() runs the closure inside the pool, so par_iter uses its workers
+    let (pool_sum, pool_threads) = pool.install(|| {
+        let sum: u64 = workload[..1_000_000].par_iter().map(|&x| expensive(x)).sum();
+        (sum, rayon::current_num_threads())
+    });
+    let global_sum: u64 = workload[..1_000_000].par_iter().map(|&x| expensive(x)).sum();
+    
+    println!("\nCustom ThreadPool:");
+    println!("  Configured threads: {} (global pool has {})", pool.current_num_threads(), rayon::current_num_threads());
+    println!("  Threads seen inside install(): {}", pool_threads);
+    println!("  Pool result matches global pool: {}", pool_sum == global_sum);
+    
     println!();
 }
 
@@ -922,6 +1544,37 @@ pub fn demo_12_tracing() {
     user_login(123, "alice");
     user_login(456, "admin");
     
+    // Routing events to a file as JSON, one object per line. with_default
+    // scopes this subscriber to the closure, so the global stdout one above
+    // is left in place.
+    use std::io::{BufRead, BufReader};
+    use std::sync::Mutex;
+    
+    let log_file = tempfile::NamedTempFile::new().unwrap();
+    let writer = Mutex::new(log_file.reopen().unwrap());
+    let json_subscriber = tracing_subscriber::fmt()
+        .json()
+        .with_max_level(Level::INFO)
+        .with_writer(writer)
+        .finish();
+    
+    tracing::subscriber::with_default(json_subscriber, || {
+        let _ = process_order(777, 999);
+        user_login(789, "bob");
+    });
+    
+    let lines: Vec<String> = BufReader::new(log_file.reopen().unwrap())
+        .lines()
+        .map(|line| line.unwrap())
+        .collect();
+    println!("\nJSON log file captured {} events", lines.len());
+    
+    // Each line parses back into structured data
+    let first: serde_json::Value = serde_json::from_str(&lines[0]).unwrap();
+    println!("First line: {}", lines[0]);
+    println!("  level = {}, message = {}", first["level"], first["fields"]["message"]);
+    println!("  order_id field = {}", first["fields"]["order_id"]);
+    
     println!();
 }
 
@@ -962,6 +1615,7 @@ pub fn demo_13_log() {
 }
 
 /// Demo 14: Itertools - Extended Iterator Methods
+#[allow(clippy::useless_vec)]
 pub fn demo_14_itertools() {
     println!("=== Demo 14: Itertools ===");
     
@@ -981,6 +1635,8 @@ pub fn demo_14_itertools() {
     println!("  Words: {:?}", words);
     println!("  Joined: {}", joined);
     
+    crate::lecture_utils::step("Next: group_by and chunks");
+    
     // Group by
     let data = vec!["apple", "apricot", "banana", "blueberry", "cherry", "coconut"];
     println!("\nGroup by first letter:");
@@ -996,6 +1652,8 @@ pub fn demo_14_itertools() {
         println!("  {:?}", chunk);
     }
     
+    crate::lecture_utils::step("Next: cartesian products");
+    
     // Cartesian product
     let colors = ["red", "green", "blue"];
     let sizes = ["S", "M", "L"];
@@ -1004,6 +1662,8 @@ pub fn demo_14_itertools() {
         println!("  {}-{}", color, size);
     }
     
+    crate::lecture_utils::step("Next: combinations and permutations");
+    
     // Combinations
     let items = ["A", "B", "C", "D"];
     println!("\nCombinations of 2:");
@@ -1018,6 +1678,8 @@ pub fn demo_14_itertools() {
         println!("  {:?}", perm);
     }
     
+    crate::lecture_utils::step("Next: intersperse and sorted dedup");
+    
     // Intersperse
     let numbers = [1, 2, 3, 4, 5];
     let interspersed: Vec<i32> = itertools::Itertools::intersperse(numbers.iter().cloned(), 0).collect();
@@ -1036,15 +1698,21 @@ pub fn demo_14_itertools() {
 }
 
 /// Demo 15: Once_cell - Lazy Static Initialization
+#[allow(clippy::useless_format)]
 pub fn demo_15_once_cell() {
     println!("=== Demo 15: Once_cell ===");
     
     use once_cell::sync::{Lazy, OnceCell};
     use std::collections::HashMap;
+    use std::sync::Mutex;
+    
+    // Records which initializer ran when, across both APIs
+    static INIT_ORDER: Mutex<Vec<&'static str>> = Mutex::new(Vec::new());
     
     // Lazy static initialization
     static CONFIG: Lazy<HashMap<&'static str, &'static str>> = Lazy::new(|| {
         println!("  Initializing global config (this happens only once)");
+        INIT_ORDER.lock().unwrap().push("once_cell::sync::Lazy (CONFIG)");
         let mut m = HashMap::new();
         m.insert("version", "1.0.0");
         m.insert("author", "Rust Demo");
@@ -1079,6 +1747,7 @@ pub fn demo_15_once_cell() {
     fn get_runtime_config() -> &'static str {
         RUNTIME_CONFIG.get_or_init(|| {
             println!("  Initializing runtime config");
+            INIT_ORDER.lock().unwrap().push("once_cell::sync::OnceCell (RUNTIME_CONFIG)");
             format!("Runtime config initialized at startup")
         })
     }
@@ -1087,6 +1756,39 @@ pub fn demo_15_once_cell() {
     println!("  First call: {}", get_runtime_config());
     println!("  Second call: {}", get_runtime_config());
     
+    // The same patterns with the standard library (LazyLock/OnceLock, Rust 1.80+)
+    use std::sync::{LazyLock, OnceLock};
+    
+    static STD_CONFIG: LazyLock<HashMap<&'static str, &'static str>> = LazyLock::new(|| {
+        println!("  [std] Initializing global config (this happens only once)");
+        INIT_ORDER.lock().unwrap().push("std::sync::LazyLock (STD_CONFIG)");
+        HashMap::from([("version", "1.0.0"), ("author", "Rust Demo"), ("debug", "true")])
+    });
+    
+    static STD_RUNTIME_CONFIG: OnceLock<String> = OnceLock::new();
+    
+    fn get_std_runtime_config() -> &'static str {
+        STD_RUNTIME_CONFIG.get_or_init(|| {
+            println!("  [std] Initializing runtime config");
+            INIT_ORDER.lock().unwrap().push("std::sync::OnceLock (STD_RUNTIME_CONFIG)");
+            "Runtime config initialized at startup".to_string()
+        })
+    }
+    
+    println!("\nStandard library equivalents:");
+    println!("  Version: {}", STD_CONFIG.get("version").unwrap());
+    println!("  Author: {}", STD_CONFIG.get("author").unwrap());
+    println!("  First call: {}", get_std_runtime_config());
+    println!("  Second call: {}", get_std_runtime_config());
+    
+    println!("\nInitialization order:");
+    for (i, name) in INIT_ORDER.lock().unwrap().iter().enumerate() {
+        println!("  {}. {}", i + 1, name);
+    }
+    
+    println!("\nNote: prefer std's LazyLock/OnceLock in new code. Reach for once_cell when");
+    println!("you need an older MSRV, the single-threaded unsync types, or get_or_try_init.");
+    
     println!();
 }
 
@@ -1094,6 +1796,7 @@ pub fn demo_15_once_cell() {
 pub fn demo_16_uuid() {
     println!("=== Demo 16: UUID ===");
     
+    use itertools::Itertools;
     use uuid::Uuid;
     
     // Generate different types of UUIDs
@@ -1148,10 +1851,41 @@ pub fn demo_16_uuid() {
         println!("  User {}: {}", user.name, user.id);
     }
     
+    // Version 7 (time-ordered) - a millisecond Unix timestamp followed by random bits
+    println!("\nTime-ordered UUIDs (v7):");
+    let mut v7_ids = Vec::new();
+    let mut v4_ids = Vec::new();
+    for _ in 0..4 {
+        v7_ids.push(Uuid::now_v7());
+        v4_ids.push(Uuid::new_v4());
+        std::thread::sleep(Duration::from_millis(2));  // Distinct timestamps
+    }
+    
+    for (i, id) in v7_ids.iter().enumerate() {
+        println!("  Created #{}: {} (version {})", i + 1, id, id.get_version_num());
+    }
+    
+    // Sorting the string form matches creation order for v7, but not for v4
+    let sorted_v7 = v7_ids.iter().map(|id| id.to_string()).sorted().collect::<Vec<_>>();
+    let created_v7 = v7_ids.iter().map(|id| id.to_string()).collect::<Vec<_>>();
+    let sorted_v4 = v4_ids.iter().map(|id| id.to_string()).sorted().collect::<Vec<_>>();
+    let created_v4 = v4_ids.iter().map(|id| id.to_string()).collect::<Vec<_>>();
+    println!("  v7 sorted order == creation order: {}", sorted_v7 == created_v7);
+    println!("  v4 sorted order == creation order: {} (random, usually false)", sorted_v4 == created_v4);
+    
+    // The creation time can be read back out of a v7 UUID
+    if let Some(timestamp) = v7_ids[0].get_timestamp() {
+        let (secs, nanos) = timestamp.to_unix();
+        if let Some(created) = chrono::DateTime::from_timestamp(secs as i64, nanos) {
+            println!("  Embedded timestamp of #1: {}", created.format("%Y-%m-%d %H:%M:%S%.3f UTC"));
+        }
+    }
+    
     println!();
 }
 
 /// Demo 17: Tempfile - Temporary File Management
+#[allow(clippy::manual_flatten)]
 pub fn demo_17_tempfile() {
     println!("=== Demo 17: Tempfile ===");
     
@@ -1270,6 +2004,36 @@ pub fn demo_18_bitflags() {
     println!("  Difference (-): {:?}", perms1 - perms2);
     println!("  Symmetric difference (^): {:?}", perms1 ^ perms2);
     
+    // Iterating the individual flags in a set
+    println!("\nFlag iteration:");
+    for flag in user_perms.iter() {
+        println!("  User has flag: {:?} (bits: {:08b})", flag, flag.bits());
+    }
+    for (name, flag) in admin_perms.iter_names() {
+        println!("  Admin flag {} = {:#04x}", name, flag.bits());
+    }
+    
+    // Parsing a set from text like "READ | WRITE" by looking up each flag's name
+    fn parse_permissions(input: &str) -> Result<Permissions, String> {
+        let mut perms = Permissions::empty();
+        for name in input.split('|').map(str::trim).filter(|name| !name.is_empty()) {
+            let (_, flag) = Permissions::all()
+                .iter_names()
+                .find(|(flag_name, _)| *flag_name == name)
+                .ok_or_else(|| format!("unknown permission '{}'", name))?;
+            perms |= flag;
+        }
+        Ok(perms)
+    }
+    
+    println!("\nParsing flags from strings:");
+    for input in ["READ | WRITE", "EXECUTE|DELETE|ADMIN", "READ | FLY"] {
+        match parse_permissions(input) {
+            Ok(perms) => println!("  '{}' -> {:?}", input, perms),
+            Err(e) => println!("  '{}' -> Error: {}", input, e),
+        }
+    }
+    
     println!();
 }
 
@@ -1277,7 +2041,8 @@ pub fn demo_18_bitflags() {
 pub fn demo_19_parking_lot() {
     println!("=== Demo 19: Parking_lot ===");
     
-    use parking_lot::{Mutex, RwLock};
+    use parking_lot::{Condvar, Mutex, MutexGuard, RwLock};
+    use std::collections::VecDeque;
     use std::sync::Arc;
     use std::thread;
     use std::time::{Duration, Instant};
@@ -1360,6 +2125,69 @@ pub fn demo_19_parking_lot() {
         None => println!("  Still couldn't get lock"),
     }
     
+    // Condvar with timeouts - no poisoning, and wait_for reports whether it timed out
+    println!("\nCondvar producer/consumer with wait_for:");
+    let pair = Arc::new((Mutex::new((VecDeque::new(), false)), Condvar::new()));  // (queue, done)
+    
+    let producer_pair = Arc::clone(&pair);
+    let producer = thread::spawn(move || {
+        let (lock, cvar) = &*producer_pair;
+        for (item, delay_ms) in [(1, 50), (2, 350), (3, 50)] {
+            thread::sleep(Duration::from_millis(delay_ms));
+            lock.lock().0.push_back(item);
+            cvar.notify_one();
+        }
+        lock.lock().1 = true;
+        cvar.notify_one();
+    });
+    
+    let (lock, cvar) = &*pair;
+    let mut state = lock.lock();
+    loop {
+        if let Some(item) = state.0.pop_front() {
+            println!("  Consumer received item {}", item);
+            continue;
+        }
+        if state.1 {
+            break;
+        }
+        // Atomically releases the lock while waiting, re-acquires it before returning
+        let result = cvar.wait_for(&mut state, Duration::from_millis(150));
+        if result.timed_out() {
+            println!("  Consumer waited 150ms with no item (timed out), waiting again");
+        }
+    }
+    drop(state);
+    producer.join().unwrap();
+    
+    // try_lock_for - bounded blocking, which std::sync::Mutex doesn't offer
+    println!("\nTimed lock acquisition and fair unlocking:");
+    let shared = Arc::new(Mutex::new(0));
+    let holder_lock = Arc::clone(&shared);
+    let (locked_tx, locked_rx) = std::sync::mpsc::channel();
+    
+    let holder = thread::spawn(move || {
+        let mut guard = holder_lock.lock();
+        locked_tx.send(()).unwrap();
+        thread::sleep(Duration::from_millis(100));
+        *guard += 1;
+        // unlock_fair hands the lock straight to a waiting thread instead of racing it
+        MutexGuard::unlock_fair(guard);
+    });
+    
+    locked_rx.recv().unwrap();  // Wait until the holder really has the lock
+    match shared.try_lock_for(Duration::from_millis(20)) {
+        Some(_) => println!("  try_lock_for(20ms): acquired"),
+        None => println!("  try_lock_for(20ms): timed out, holder is still busy"),
+    }
+    
+    let start = Instant::now();
+    match shared.try_lock_for(Duration::from_millis(500)) {
+        Some(value) => println!("  try_lock_for(500ms): acquired after {:?}, value = {}", start.elapsed(), *value),
+        None => println!("  try_lock_for(500ms): timed out"),
+    }
+    holder.join().unwrap();
+    
     println!();
 }
 
@@ -1444,9 +2272,552 @@ pub fn demo_20_advanced_collections() {
     println!();
 }
 
+/// Demo 21: CSV Parsing - Tabular data with a hand-rolled splitter + serde typing
+pub fn demo_21_csv() {
+    println!("=== Demo 21: CSV Parsing ===");
+    
+    use serde::{Deserialize, Deserializer, Serialize};
+    use serde_json::{Map, Value};
+    
+    #[derive(Serialize, Deserialize, Debug)]
+    struct Record {
+        name: String,
+        city: String,
+        #[serde(deserialize_with = "from_text")]
+        age: u32,
+        #[serde(deserialize_with = "from_text")]
+        score: f64,
+    }
+    
+    // CSV has no types: every field is text, so numeric columns opt in to parsing
+    fn from_text<'de, D, T>(deserializer: D) -> Result<T, D::Error>
+    where
+        D: Deserializer<'de>,
+        T: std::str::FromStr,
+        T::Err: std::fmt::Display,
+    {
+        let text = String::deserialize(deserializer)?;
+        text.parse().map_err(serde::de::Error::custom)
+    }
+    
+    // Split one CSV line into fields, honoring quotes ("a, b") and escaped quotes ("")
+    fn split_csv_line(line: &str) -> Vec<String> {
+        let mut fields = Vec::new();
+        let mut current = String::new();
+        let mut in_quotes = false;
+        let mut chars = line.chars().peekable();
+        
+        while let Some(c) = chars.next() {
+            match c {
+                '"' if in_quotes && chars.peek() == Some(&'"') => {
+                    current.push('"');
+                    chars.next();
+                }
+                '"' => in_quotes = !in_quotes,
+                ',' if !in_quotes => fields.push(std::mem::take(&mut current)),
+                _ => current.push(c),
+            }
+        }
+        fields.push(current);
+        fields
+    }
+    
+    // Key every field by its header as a string, then let serde type the row
+    fn parse_record(headers: &[String], line: &str) -> Result<Record, String> {
+        let fields = split_csv_line(line);
+        if fields.len() != headers.len() {
+            return Err(format!("expected {} fields, found {}", headers.len(), fields.len()));
+        }
+        
+        let row: Map<String, Value> = headers.iter().cloned()
+            .zip(fields.into_iter().map(Value::String))
+            .collect();
+        serde_json::from_value(Value::Object(row)).map_err(|e| e.to_string())
+    }
+    
+    let csv_data = r#"name,city,age,score
+"Johnson, Alice",Portland,30,91.5
+Bob Smith,"New York",25,78
+"Carol ""CJ"" Jones",Austin,41,88.25"#;
+    
+    let mut lines = csv_data.lines();
+    let headers = split_csv_line(lines.next().unwrap());
+    println!("Header row: {:?}", headers);
+    
+    let records: Vec<Record> = lines
+        .map(|line| parse_record(&headers, line))
+        .collect::<Result<_, _>>()
+        .unwrap();
+    
+    println!("\nParsed {} records:", records.len());
+    for record in &records {
+        println!("  {:<20} {:<10} age {:>2}  score {:>6.2}", record.name, record.city, record.age, record.score);
+    }
+    
+    // Because Record derives Serialize, it converts straight to JSON (see Demo 1)
+    println!("\nFirst record as JSON:");
+    println!("  {}", serde_json::to_string(&records[0]).unwrap());
+    
+    // Malformed rows surface as errors instead of bad data
+    println!("\nMalformed rows:");
+    let bad_rows = [
+        "Dave,Denver,not_a_number,70.0",
+        "Eve,Boston,33",
+    ];
+    for line in bad_rows {
+        match parse_record(&headers, line) {
+            Ok(record) => println!("  Unexpected success: {:?}", record),
+            Err(e) => println!("  '{}' -> Error: {}", line, e),
+        }
+    }
+    
+    println!();
+}
+
+/// Demo 22: TOML - Typed configuration files with serde
+pub fn demo_22_toml() {
+    println!("=== Demo 22: TOML ===");
+    
+    use serde::{Deserialize, Serialize};
+    
+    #[derive(Serialize, Deserialize, Debug)]
+    struct Config {
+        name: String,
+        version: String,
+        server: Server,
+        database: Database,
+        features: Vec<String>,
+    }
+    
+    #[derive(Serialize, Deserialize, Debug)]
+    struct Server {
+        host: String,
+        port: u16,
+    }
+    
+    #[derive(Serialize, Deserialize, Debug)]
+    struct Database {
+        url: String,
+        #[serde(default)]
+        pool_size: u32,
+        replicas: Vec<Replica>,
+    }
+    
+    #[derive(Serialize, Deserialize, Debug)]
+    struct Replica {
+        host: String,
+        read_only: bool,
+    }
+    
+    let source = r#"
+name = "lecture-app"
+version = "1.2.0"
+features = ["metrics", "tracing"]
+
+[server]
+host = "127.0.0.1"
+port = 8080
+
+[database]
+url = "postgres://localhost/app"
+pool_size = 16
+
+[[database.replicas]]
+host = "replica-a"
+read_only = true
+
+[[database.replicas]]
+host = "replica-b"
+read_only = false
+"#;
+    
+    // Tables become nested structs, [[arrays of tables]] become Vecs
+    let config: Config = toml::from_str(source).unwrap();
+    println!("Parsed {} v{}", config.name, config.version);
+    println!("  Server: {}:{}", config.server.host, config.server.port);
+    println!("  Database: {} (pool {})", config.database.url, config.database.pool_size);
+    for replica in &config.database.replicas {
+        println!("  Replica: {} read_only={}", replica.host, replica.read_only);
+    }
+    println!("  Features: {:?}", config.features);
+    
+    // And back to TOML text
+    let serialized = toml::to_string_pretty(&config).unwrap();
+    println!("\nRe-serialized:\n{}", serialized);
+    
+    // Malformed input and type mismatches are reported with a location
+    let malformed = "name = \"broken\nversion = 1";
+    match toml::from_str::<Config>(malformed) {
+        Ok(_) => println!("Unexpectedly parsed malformed TOML"),
+        Err(e) => println!("Malformed TOML error:\n{}", e),
+    }
+    
+    let wrong_type = "name = \"x\"\nversion = \"1\"\nfeatures = []\n[server]\nhost = \"h\"\nport = \"eighty\"\n[database]\nurl = \"u\"\nreplicas = []";
+    match toml::from_str::<Config>(wrong_type) {
+        Ok(_) => println!("Unexpectedly parsed wrong type"),
+        Err(e) => println!("Type mismatch error:\n{}", e),
+    }
+    
+    println!();
+}
+
+/// Demo 23: Indicatif - Progress Bars and Spinners
+pub fn demo_23_indicatif() {
+    println!("=== Demo 23: Indicatif ===");
+    
+    use indicatif::{ProgressBar, ProgressStyle};
+    use std::io::IsTerminal;
+    
+    // Bars draw on stderr, so animate only when stderr is a real terminal;
+    // when it is piped (CI, tests, redirect to a file) use hidden bars and skip the sleeps
+    let animate = std::io::stderr().is_terminal();
+    let step_delay = if animate { Duration::from_millis(5) } else { Duration::ZERO };
+    if !animate {
+        println!("(Output is not a terminal, skipping the animation)");
+    }
+    
+    // Determinate task: we know the total number of steps
+    let bar = if animate { ProgressBar::new(100) } else { ProgressBar::hidden() };
+    bar.set_length(100);
+    bar.set_style(
+        ProgressStyle::with_template("{spinner:.green} [{elapsed_precise}] [{bar:40.cyan/blue}] {pos}/{len} {msg}")
+            .unwrap()
+            .progress_chars("=> "),
+    );
+    
+    let mut checksum = 0u64;
+    for step in 0..100u64 {
+        checksum += step * step;
+        if step % 25 == 0 {
+            bar.set_message(format!("phase {}", step / 25 + 1));
+        }
+        bar.inc(1);
+        std::thread::sleep(step_delay);
+    }
+    bar.finish_with_message("done");
+    if animate {
+        eprintln!(); // The bar draws on stderr and leaves the cursor on its line
+    }
+    println!("Processed {} steps (checksum {})", bar.position(), checksum);
+    
+    // Indeterminate task: a spinner with a changing message
+    let spinner = if animate { ProgressBar::new_spinner() } else { ProgressBar::hidden() };
+    spinner.set_style(ProgressStyle::with_template("{spinner:.yellow} {msg}").unwrap());
+    for attempt in 1..=20 {
+        spinner.set_message(format!("Waiting for server (poll {})", attempt));
+        spinner.tick();
+        std::thread::sleep(step_delay * 2);
+    }
+    spinner.finish_with_message("Server is ready");
+    if animate {
+        eprintln!();
+    }
+    println!("Spinner finished: {}", spinner.message());
+    
+    println!();
+}
+
+/// Demo 24: Process - Running external commands with std::process
+pub fn demo_24_process() {
+    println!("=== Demo 24: Process ===");
+    
+    use std::io::{ErrorKind, Write};
+    use std::process::{Command, Stdio};
+    
+    // Shell built-ins like echo need a shell on Windows
+    fn shell(script: &str) -> Command {
+        if cfg!(windows) {
+            let mut cmd = Command::new("cmd");
+            cmd.args(["/C", script]);
+            cmd
+        } else {
+            let mut cmd = Command::new("sh");
+            cmd.args(["-c", script]);
+            cmd
+        }
+    }
+    
+    // output() waits for the child and captures stdout and stderr
+    match shell("echo hello from a child process").output() {
+        Ok(output) => {
+            println!("echo:");
+            println!("  status: {}", output.status);
+            println!("  stdout: {:?}", String::from_utf8_lossy(&output.stdout).trim_end());
+        }
+        Err(e) => println!("echo failed to start: {}", e),
+    }
+    
+    // A non-zero exit code is not an Err: check status.success()
+    let listing = if cfg!(windows) { "dir /B" } else { "ls" };
+    match shell(listing).output() {
+        Ok(output) if output.status.success() => {
+            let stdout = String::from_utf8_lossy(&output.stdout);
+            let entries: Vec<&str> = stdout.lines().collect();
+            println!("\n{} -> {} entries in the current directory, first few: {:?}", listing, entries.len(), &entries[..entries.len().min(3)]);
+        }
+        Ok(output) => println!("\n{} exited with {}", listing, output.status),
+        Err(e) => println!("\n{} failed to start: {}", listing, e),
+    }
+    
+    match shell("exit 3").status() {
+        Ok(status) => println!("\n'exit 3' -> success: {}, code: {:?}", status.success(), status.code()),
+        Err(e) => println!("\n'exit 3' failed to start: {}", e),
+    }
+    
+    // Piping input: write to the child's stdin, then read its stdout
+    let sort = Command::new("sort")
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .spawn();
+    match sort {
+        Ok(mut child) => {
+            // Dropping stdin closes the pipe so sort knows the input is complete
+            if let Some(mut stdin) = child.stdin.take() {
+                stdin.write_all(b"pear\napple\nfig\n").unwrap();
+            }
+            let output = child.wait_with_output().unwrap();
+            let sorted: Vec<String> = String::from_utf8_lossy(&output.stdout).lines().map(String::from).collect();
+            println!("\nPiped \"pear, apple, fig\" through sort: {:?}", sorted);
+        }
+        Err(e) => println!("\nsort failed to start: {}", e),
+    }
+    
+    // A missing program is an io::Error with kind NotFound, not a panic
+    match Command::new("definitely-not-a-real-program").output() {
+        Ok(_) => println!("\nUnexpectedly found the program"),
+        Err(e) if e.kind() == ErrorKind::NotFound => println!("\nMissing program -> NotFound: {}", e),
+        Err(e) => println!("\nMissing program -> other error: {}", e),
+    }
+    
+    println!();
+}
+
+/// Demo 25: Filesystem - Recursive directory walking with std::fs
+pub fn demo_25_filesystem() {
+    println!("=== Demo 25: Filesystem ===");
+    
+    use std::fs;
+    use std::io;
+    use std::path::{Path, PathBuf};
+    use tempfile::TempDir;
+    
+    // Build a small project tree inside a TempDir, which is deleted on drop
+    let root = TempDir::new().unwrap();
+    let files: [(&str, usize); 6] = [
+        ("Cargo.toml", 120),
+        ("README.md", 2048),
+        ("src/main.rs", 512),
+        ("src/lib.rs", 300),
+        ("src/utils/mod.rs", 64),
+        ("tests/it.rs", 256),
+    ];
+    for (relative, size) in files {
+        let path = root.path().join(relative);
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(&path, vec![b'x'; size]).unwrap();
+    }
+    fs::create_dir_all(root.path().join("target/empty")).unwrap();
+    
+    // Hand-rolled walk: recurse into directories, record (path, size) for files
+    fn walk(dir: &Path, found: &mut Vec<(PathBuf, u64)>) -> io::Result<()> {
+        for entry in fs::read_dir(dir)? {
+            let entry = entry?;
+            let file_type = entry.file_type()?;
+            if file_type.is_dir() {
+                walk(&entry.path(), found)?;
+            } else if file_type.is_file() {
+                found.push((entry.path(), entry.metadata()?.len()));
+            }
+        }
+        Ok(())
+    }
+    
+    let mut found = Vec::new();
+    walk(root.path(), &mut found).unwrap();
+    // read_dir order is platform-dependent, so sort for stable output
+    found.sort();
+    
+    println!("Walking a temporary project tree:");
+    for (path, size) in &found {
+        let relative = path.strip_prefix(root.path()).unwrap();
+        println!("  {:<18} {:>5} bytes", relative.display().to_string(), size);
+    }
+    let total: u64 = found.iter().map(|(_, size)| size).sum();
+    println!("{} files, {} bytes total", found.len(), total);
+    
+    // Errors surface as io::Result instead of panics
+    match walk(&root.path().join("does-not-exist"), &mut Vec::new()) {
+        Ok(()) => println!("Unexpectedly walked a missing directory"),
+        Err(e) => println!("Walking a missing directory -> {:?}: {}", e.kind(), e),
+    }
+    
+    let root_path = root.path().to_path_buf();
+    drop(root);
+    println!("After dropping the TempDir, tree exists: {}", root_path.exists());
+    
+    println!();
+}
+
+/// Demo 26: Env - Configuration from environment variables
+pub fn demo_26_env() {
+    println!("=== Demo 26: Env ===");
+    
+    use std::env::{self, VarError};
+    use std::process::Command;
+    
+    // var() returns Result<String, VarError>; fall back to a default when unset
+    let log_level = env::var("LECTURE_LOG_LEVEL").unwrap_or_else(|_| "info".to_string());
+    println!("LECTURE_LOG_LEVEL = {:?} (default \"info\" if unset)", log_level);
+    
+    // Parse into a typed value, treating both "missing" and "garbage" as the default
+    let workers: usize = env::var("LECTURE_WORKERS")
+        .ok()
+        .and_then(|v| v.parse().ok())
+        .unwrap_or(4);
+    println!("LECTURE_WORKERS = {} (parsed as usize, default 4)", workers);
+    
+    // Matching on VarError tells "not set" apart from "not valid Unicode"
+    match env::var("LECTURE_SURELY_NOT_SET") {
+        Ok(value) => println!("Unexpectedly set: {}", value),
+        Err(VarError::NotPresent) => println!("LECTURE_SURELY_NOT_SET -> VarError::NotPresent"),
+        Err(VarError::NotUnicode(raw)) => println!("Not valid Unicode: {:?}", raw),
+    }
+    
+    // vars() iterates the whole environment; pick out a few well-known keys
+    let known = ["HOME", "USERPROFILE", "PATH", "SHELL", "LANG"];
+    println!("\nKnown variables from env::vars():");
+    let mut matches: Vec<(String, String)> = env::vars().filter(|(k, _)| known.contains(&k.as_str())).collect();
+    matches.sort();
+    for (key, value) in &matches {
+        let shown: String = value.chars().take(40).collect();
+        println!("  {:<11} = {}{}", key, shown, if value.chars().count() > 40 { "..." } else { "" });
+    }
+    
+    // env::set_var is unsafe in the 2024 edition (other threads may be reading the env),
+    // so scope overrides to a child process with Command::env instead
+    let script = if cfg!(windows) { "echo %LECTURE_GREETING%" } else { "echo $LECTURE_GREETING" };
+    let mut child = if cfg!(windows) { Command::new("cmd") } else { Command::new("sh") };
+    child.args(if cfg!(windows) { ["/C", script] } else { ["-c", script] });
+    match child.env("LECTURE_GREETING", "hello from the child's environment").output() {
+        Ok(output) => println!("\nChild saw LECTURE_GREETING = {:?}", String::from_utf8_lossy(&output.stdout).trim_end()),
+        Err(e) => println!("\nCould not start child: {}", e),
+    }
+    println!("Parent still has LECTURE_GREETING: {}", env::var("LECTURE_GREETING").is_ok());
+    
+    println!();
+}
+
+/// Demo 27: Base64 - Encoding binary data as text
+pub fn demo_27_base64() {
+    println!("=== Demo 27: Base64 ===");
+    
+    use base64::engine::general_purpose::{STANDARD, URL_SAFE_NO_PAD};
+    use base64::Engine;
+    use uuid::Uuid;
+    
+    // Bytes chosen so the standard alphabet needs '+' and '/'
+    let bytes: &[u8] = &[0xfb, 0xff, 0xbf, 0x00, 0x10, 0x83];
+    let standard = STANDARD.encode(bytes);
+    let url_safe = URL_SAFE_NO_PAD.encode(bytes);
+    println!("Bytes:    {:02x?}", bytes);
+    println!("Standard: {}", standard);
+    println!("URL-safe: {} ('-' and '_' instead of '+' and '/', no padding)", url_safe);
+    
+    let text = "Rust 🦀";
+    println!("\n{:?} -> {}", text, STANDARD.encode(text));
+    
+    // A UUID is 16 raw bytes: 22 chars in URL-safe base64 vs 36 in hex-with-dashes
+    let id = Uuid::new_v4();
+    let compact = URL_SAFE_NO_PAD.encode(id.as_bytes());
+    println!("\nUUID {} ({} chars)", id, id.to_string().len());
+    println!("  as URL-safe base64: {} ({} chars)", compact, compact.len());
+    
+    // Decoding returns a Result; round-trips must give back the original bytes
+    let decoded = STANDARD.decode(&standard).unwrap();
+    assert_eq!(decoded, bytes);
+    let decoded_id = Uuid::from_slice(&URL_SAFE_NO_PAD.decode(&compact).unwrap()).unwrap();
+    assert_eq!(decoded_id, id);
+    println!("\nRound trips: bytes ok = {}, uuid ok = {}", decoded == bytes, decoded_id == id);
+    
+    // Invalid input is an error, not a panic; mixing alphabets is a common culprit
+    for bad in ["not base64!", url_safe.as_str()] {
+        match STANDARD.decode(bad) {
+            Ok(data) => println!("Decoded {:?} -> {:02x?}", bad, data),
+            Err(e) => println!("Decoding {:?} with STANDARD -> error: {}", bad, e),
+        }
+    }
+    
+    println!();
+}
+
+/// Demo 28: Hashing - SHA-256 digests for integrity and caching
+pub fn demo_28_hashing() {
+    println!("=== Demo 28: Hashing ===");
+    
+    use sha2::{Digest, Sha256};
+    use std::io::{Read, Write};
+    use tempfile::NamedTempFile;
+    
+    // One-shot hashing of a string
+    let digest = Sha256::digest(b"hello world");
+    println!("sha256(\"hello world\") = {:x}", digest);
+    
+    // Same input, same digest; one changed byte scrambles the whole thing
+    let a = Sha256::digest(b"The quick brown fox");
+    let b = Sha256::digest(b"The quick brown fox");
+    let c = Sha256::digest(b"The quick brown fix");
+    println!("\n\"The quick brown fox\" twice: equal = {}", a == b);
+    println!("  fox: {:x}", a);
+    println!("  fix: {:x}", c);
+    let differing_bytes = a.iter().zip(c.iter()).filter(|(x, y)| x != y).count();
+    println!("  {} of 32 digest bytes differ after a one-byte change", differing_bytes);
+    
+    // Hashing a file in chunks, so large files never need to fit in memory
+    let mut file = NamedTempFile::new().unwrap();
+    for line in 0..1000 {
+        writeln!(file, "log line {}", line).unwrap();
+    }
+    file.flush().unwrap();
+    
+    let mut hasher = Sha256::new();
+    let mut reader = file.reopen().unwrap();
+    let mut buffer = [0u8; 4096];
+    let mut total = 0;
+    loop {
+        let n = reader.read(&mut buffer).unwrap();
+        if n == 0 {
+            break;
+        }
+        hasher.update(&buffer[..n]);
+        total += n;
+    }
+    let file_digest = hasher.finalize();
+    println!("\nFile of {} bytes, streamed in 4 KiB chunks:", total);
+    println!("  {:x}", file_digest);
+    
+    // Streaming and one-shot hashing agree
+    let whole = std::fs::read(file.path()).unwrap();
+    println!("  Matches one-shot digest of the same bytes: {}", Sha256::digest(&whole) == file_digest);
+    
+    println!();
+}
+
+/// Header printed by `run_all_demos`
+pub const BANNER: &str = concat!(crab!(), " RUST LECTURE - SECTION 8: POPULAR CRATE DEMONSTRATIONS ", crab!());
+
+/// Concepts listed in the recap printed after `run_all_demos`
+pub const RECAP: &[&str] = &[
+    "serde maps Rust types to JSON, TOML and more",
+    "tokio runs async code; reqwest speaks HTTP",
+    "anyhow and thiserror cover app and library errors",
+    "rayon and crossbeam parallelize and coordinate work",
+    "tracing and log give structured diagnostics",
+    "regex, chrono and uuid fill gaps in std",
+];
+
 /// Run all crate demonstrations
 pub fn run_all_demos() {
-    println!("🦀 RUST LECTURE - SECTION 8: POPULAR CRATE DEMONSTRATIONS 🦀");
+    println!("{}", BANNER);
     println!("==============================================================");
     println!();
     
@@ -1470,15 +2841,25 @@ pub fn run_all_demos() {
     demo_18_bitflags();
     demo_19_parking_lot();
     demo_20_advanced_collections();
-    
-    println!("✅ Section 8 complete!");
-    println!("💡 Key takeaway: Rust's crate ecosystem provides powerful, well-designed libraries for every need!");
+    demo_21_csv();
+    demo_22_toml();
+    demo_23_indicatif();
+    demo_24_process();
+    demo_25_filesystem();
+    demo_26_env();
+    demo_27_base64();
+    demo_28_hashing();
+    
+    println!(concat!(check!(), " Section 8 complete!"));
+    crate::lecture_utils::print_recap("Section 8: Popular Crates", RECAP);
 }
 
 /// Get list of available demos for enhanced navigation
 pub fn get_demo_list() -> Vec<&'static str> {
     vec![
-        "1", "2", "3", "4", "5", "6", "7", "8", "9", "10",
-        "11", "12", "13", "14", "15", "16", "17", "18", "19", "20",
+        "serde", "rand", "clap", "tokio", "reqwest", "regex", "chrono",
+        "anyhow", "thiserror", "crossbeam", "rayon", "tracing", "log", "itertools",
+        "once_cell", "uuid", "tempfile", "bitflags", "parking_lot", "collections", "csv",
+        "toml", "indicatif", "process", "filesystem", "env", "base64", "hashing",
     ]
 }
\ No newline at end of file
@@ -0,0 +1,145 @@
+//! Smoke tests that run every registered demo and check none of them panic.
+
+use lecture::{all_demos, Section};
+use lecture::demo_runner::run_individual_demo;
+use std::panic;
+
+/// Demos that need network access; run them with `cargo test -- --ignored`
+const NETWORK_DEMOS: &[(u8, &str)] = &[(8, "reqwest")];
+
+#[test]
+fn every_demo_runs_without_panicking() {
+    let mut failures = Vec::new();
+    
+    for demo in all_demos() {
+        if NETWORK_DEMOS.contains(&(demo.section, demo.key)) {
+            continue;
+        }
+        
+        let section = Section::try_from(demo.section).unwrap();
+        match panic::catch_unwind(|| run_individual_demo(section, demo.key)) {
+            Ok(true) => {}
+            Ok(false) => failures.push(format!("section {} / {} is not routed", demo.section, demo.key)),
+            Err(_) => failures.push(format!("section {} / {} panicked", demo.section, demo.key)),
+        }
+    }
+    
+    assert!(failures.is_empty(), "demos failed: {:?}", failures);
+}
+
+#[test]
+#[ignore = "requires network access"]
+fn network_demos_run_without_panicking() {
+    for &(section, key) in NETWORK_DEMOS {
+        assert!(run_individual_demo(Section::try_from(section).unwrap(), key), "section {} / {} is not routed", section, key);
+    }
+}
+
+#[test]
+fn manifest_covers_every_section_demo() {
+    use lecture::*;
+    
+    // A demo added to a section's list but not to all_demos() (or registered
+    // under a different key) would be missing from the manifest and from the
+    // panic check above, which also verifies that every key is routed
+    let section_lists = [
+        section1_basics::get_demo_list(),
+        section2_ownership::get_demo_list(),
+        section3_borrowing::get_demo_list(),
+        section4_traits::get_demo_list(),
+        section5_enums::get_demo_list(),
+        section6_idioms::get_demo_list(),
+        section7_concurrency::get_demo_list(),
+        section8_crates::get_demo_list(),
+    ];
+    let demos = all_demos();
+    
+    for (index, list) in section_lists.iter().enumerate() {
+        let section = index as u8 + 1;
+        let registered: Vec<&str> = demos.iter().filter(|d| d.section == section).map(|d| d.key).collect();
+        assert_eq!(&registered, list, "section {} manifest keys differ from its demo list", section);
+    }
+    
+    assert!(!run_individual_demo(Section::Basics, "no_such_demo"), "unknown keys must be reported");
+}
+
+#[test]
+fn recap_lines_fit_separator_width() {
+    use lecture::*;
+    use lecture::lecture_utils::{recap_lines, SEPARATOR_WIDTH};
+    
+    let recaps = [
+        section1_basics::RECAP,
+        section2_ownership::RECAP,
+        section3_borrowing::RECAP,
+        section4_traits::RECAP,
+        section5_enums::RECAP,
+        section6_idioms::RECAP,
+        section7_concurrency::RECAP,
+        section8_crates::RECAP,
+    ];
+    
+    for (section, recap) in Section::ALL.into_iter().zip(recaps) {
+        let name = format!("Section {}: {}", section.number(), section);
+        for line in recap_lines(&name, recap) {
+            assert_eq!(line.chars().count(), SEPARATOR_WIDTH, "recap line overflows the box: {:?}", line);
+        }
+    }
+}
+
+#[test]
+#[cfg(feature = "ascii")]
+fn ascii_feature_banners_contain_no_emoji() {
+    use lecture::*;
+    
+    let banners = [
+        demo_runner::MAIN_BANNER,
+        demo_runner::RUN_ALL_BANNER,
+        demo_runner::NAVIGATION_BANNER,
+        demo_runner::ALL_NAVIGATION_BANNER,
+        section1_basics::BANNER,
+        section2_ownership::BANNER,
+        section3_borrowing::BANNER,
+        section4_traits::BANNER,
+        section5_enums::BANNER,
+        section6_idioms::BANNER,
+        section7_concurrency::BANNER,
+        section8_crates::BANNER,
+        demo_runner::ALL_COMPLETED_BANNER,
+        demo_runner::SECTIONS_HEADING,
+        demo_runner::OPTIONS_HEADING,
+        demo_runner::ENHANCED_HINT,
+        demo_runner::PROGRESS_LABEL,
+        demo_runner::DONE_MARK,
+        demo_runner::MENU_HELP,
+        demo_runner::NAVIGATION_KEYS,
+        demo_runner::DRY_RUN_HEADING,
+        lecture_utils::DEMO_MARKER,
+        lecture_utils::PAUSE_MARKER,
+        lecture_utils::STEP_MARKER,
+        lecture_utils::CONCEPT_MARKER,
+        lecture_utils::CELEBRATE_MARKER,
+        lecture_utils::SEQUENCE_MARKER,
+    ];
+    
+    for banner in banners {
+        assert!(banner.is_ascii(), "non-ASCII bytes in banner {:?}", banner);
+    }
+    
+    let recaps = [
+        section1_basics::RECAP,
+        section2_ownership::RECAP,
+        section3_borrowing::RECAP,
+        section4_traits::RECAP,
+        section5_enums::RECAP,
+        section6_idioms::RECAP,
+        section7_concurrency::RECAP,
+        section8_crates::RECAP,
+    ];
+    
+    for recap in recaps {
+        for line in lecture_utils::recap_lines("Recap", recap) {
+            assert!(line.is_ascii(), "non-ASCII bytes in recap line {:?}", line);
+        }
+    }
+}